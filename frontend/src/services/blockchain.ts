@@ -98,7 +98,8 @@ class CreatefiBlockchainService {
     if (!this.api || !this.selectedAccount) throw new Error('Not connected');
 
     const injector = await web3FromAddress(this.selectedAccount.address);
-    const tx = this.api.tx.fiStablecoin.createVault(collateralType, collateralAmount);
+    const assetId = await this.getCollateralAssetId(collateralType);
+    const tx = this.api.tx.fiStablecoin.openVault(assetId, collateralAmount, 0);
 
     return new Promise((resolve, reject) => {
       tx.signAndSend(this.selectedAccount!.address, { signer: injector.signer }, ({ status }) => {
@@ -114,7 +115,7 @@ class CreatefiBlockchainService {
     if (!this.api || !this.selectedAccount) throw new Error('Not connected');
 
     const injector = await web3FromAddress(this.selectedAccount.address);
-    const tx = this.api.tx.fiStablecoin.mintFi(vaultId, amount);
+    const tx = this.api.tx.fiStablecoin.adjustVault(vaultId, null, { Increase: amount });
 
     return new Promise((resolve, reject) => {
       tx.signAndSend(this.selectedAccount!.address, { signer: injector.signer }, ({ status }) => {
@@ -192,14 +193,40 @@ class CreatefiBlockchainService {
     }));
   }
 
+  async getCollateralAssets() {
+    if (!this.api) throw new Error('Not connected');
+    const assets = await this.api.query.fiStablecoin.collateralAssets.entries();
+    return assets.map(([key, value]) => {
+      const info = value.toHuman() as any;
+      return {
+        id: key.args[0].toString(),
+        ...info
+      };
+    });
+  }
+
+  async getCollateralAssetId(symbol: string) {
+    const assets = await this.getCollateralAssets();
+    const asset = assets.find((a: any) => a.symbol === symbol);
+    if (!asset) throw new Error(`${symbol} is not an accepted collateral asset`);
+    return asset.id;
+  }
+
   async getVaults(address: string) {
     if (!this.api) throw new Error('Not connected');
-    const vaults = await this.api.query.fiStablecoin.vaults.entries();
+    const [vaults, assets] = await Promise.all([
+      this.api.query.fiStablecoin.vaults.entries(),
+      this.getCollateralAssets(),
+    ]);
     return vaults
-      .filter(([key]) => key.args[1].toString() === address)
       .map(([key, value]) => ({
         id: key.args[0].toString(),
         ...(value.toJSON() as any)
+      }))
+      .filter((vault: any) => vault.owner === address)
+      .map((vault: any) => ({
+        ...vault,
+        collateralType: assets.find((a: any) => a.id === vault.collateralAsset.toString())?.symbol
       }));
   }
 
@@ -0,0 +1,347 @@
+//! # Circuit Breaker Pallet
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! This pallet halts CREATEFI pallets, or individual calls, during an incident.
+//! The pallet is installed as the runtime's `BaseCallFilter`, so a pause takes effect for every
+//! extrinsic dispatched after it, including later ones in the same block.
+//!
+//! The guardian origin reacts quickly but only with pauses that expire after at most
+//! [`Config::MaxGuardianPause`] blocks. [`EnsureGuardian`] admits the account in [`Guardian`],
+//! set at genesis and replaced by the override origin with `set_guardian`. The override origin, standing in for DAO governance, may
+//! pause without expiry, and lift or replace any pause. Guardians cannot lift or replace an
+//! override pause. Expired pauses stop filtering immediately and are pruned in `on_idle`.
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use codec::DecodeWithMemTracking;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{CallMetadata, Contains, EnsureOrigin, GetCallMetadata},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Saturating, Zero};
+    use sp_std::vec::Vec;
+
+    /// Name of a pallet or call as reported by [`GetCallMetadata`].
+    pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching runtime event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        /// Origin allowed to pause and unpause for at most [`Config::MaxGuardianPause`] blocks.
+        type GuardianOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to pause without expiry and to lift or replace any pause.
+        type OverrideOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Pallets that can never be paused, e.g. this pallet and `System`.
+        type WhitelistedPallets: Contains<NameOf<Self>>;
+
+        /// The longest pause the guardian origin may set, in blocks.
+        #[pallet::constant]
+        type MaxGuardianPause: Get<BlockNumberFor<Self>>;
+
+        /// The maximum length of a pallet or call name.
+        #[pallet::constant]
+        type MaxNameLen: Get<u32>;
+    }
+
+    /// A pallet, or a single call of a pallet, that can be paused.
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum PauseTarget<Name> {
+        /// Every call of the pallet.
+        Pallet(Name),
+        /// A single call, by pallet and call name.
+        Call(Name, Name),
+    }
+
+    impl<Name> PauseTarget<Name> {
+        /// Name of the pallet the target belongs to.
+        pub fn pallet_name(&self) -> &Name {
+            match self {
+                PauseTarget::Pallet(pallet) | PauseTarget::Call(pallet, _) => pallet,
+            }
+        }
+    }
+
+    pub type PauseTargetOf<T> = PauseTarget<NameOf<T>>;
+
+    /// An active pause.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct PauseInfo<BlockNumber> {
+        /// First block at which the target is callable again, `None` until lifted.
+        pub expires_at: Option<BlockNumber>,
+        /// Whether the pause was set by the override origin.
+        pub by_override: bool,
+    }
+
+    /// The pallet's storage items.
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Active pauses by target.
+    #[pallet::storage]
+    #[pallet::getter(fn paused)]
+    pub type Paused<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PauseTargetOf<T>,
+        PauseInfo<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// The account admitted by [`EnsureGuardian`].
+    #[pallet::storage]
+    #[pallet::getter(fn guardian)]
+    pub type Guardian<T: Config> = StorageValue<_, <T as frame_system::Config>::AccountId, OptionQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// The initial guardian account.
+        pub guardian: Option<T::AccountId>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            if let Some(guardian) = &self.guardian {
+                Guardian::<T>::put(guardian);
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let per_pause = T::WeightInfo::prune_expired();
+            let max_pauses = remaining_weight.checked_div_per_component(&per_pause).unwrap_or(0);
+            if max_pauses.is_zero() {
+                return Weight::zero();
+            }
+
+            let mut scanned = 0u64;
+            let expired: Vec<_> = Paused::<T>::iter()
+                .take(max_pauses as usize)
+                .inspect(|_| scanned += 1)
+                .filter(|(_, info)| !Self::is_active(info, block_number))
+                .map(|(target, _)| target)
+                .collect();
+
+            for target in expired {
+                Paused::<T>::remove(&target);
+                Self::deposit_event(Event::PauseExpired { target });
+            }
+            per_pause.saturating_mul(scanned)
+        }
+    }
+
+    /// Events that functions in this pallet can emit.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A pallet or call was paused.
+        /// [target, expires_at, by_override]
+        Paused {
+            target: PauseTargetOf<T>,
+            expires_at: Option<BlockNumberFor<T>>,
+            by_override: bool,
+        },
+        /// A pause was lifted.
+        /// [target]
+        Unpaused { target: PauseTargetOf<T> },
+        /// An expired pause was pruned.
+        /// [target]
+        PauseExpired { target: PauseTargetOf<T> },
+        /// The guardian account was replaced.
+        /// [guardian]
+        GuardianSet { guardian: Option<T::AccountId> },
+    }
+
+    /// Errors that can be returned by this pallet.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The pallet is whitelisted and cannot be paused.
+        Unpausable,
+        /// The guardian origin must pause for at most `MaxGuardianPause` blocks.
+        PauseTooLong,
+        /// A pause must last at least one block.
+        InvalidDuration,
+        /// The target is not paused.
+        NotPaused,
+        /// The target was paused by the override origin.
+        PausedByOverride,
+    }
+
+    /// The pallet's dispatchable functions.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pause a pallet or call for `duration` blocks.
+        ///
+        /// The guardian origin defaults to, and may not exceed, `MaxGuardianPause`. The override
+        /// origin pauses until the target is unpaused when no duration is given. Replaces an
+        /// existing pause of the same target.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::pause())]
+        pub fn pause(
+            origin: OriginFor<T>,
+            target: PauseTargetOf<T>,
+            duration: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let by_override = Self::ensure_breaker_origin(origin, &target)?;
+            ensure!(!T::WhitelistedPallets::contains(target.pallet_name()), Error::<T>::Unpausable);
+
+            let duration = if by_override {
+                duration
+            } else {
+                let duration = duration.unwrap_or_else(T::MaxGuardianPause::get);
+                ensure!(duration <= T::MaxGuardianPause::get(), Error::<T>::PauseTooLong);
+                Some(duration)
+            };
+            ensure!(!matches!(duration, Some(d) if d.is_zero()), Error::<T>::InvalidDuration);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = duration.map(|d| now.saturating_add(d));
+            Paused::<T>::insert(&target, PauseInfo { expires_at, by_override });
+
+            Self::deposit_event(Event::Paused { target, expires_at, by_override });
+
+            Ok(())
+        }
+
+        /// Lift the pause of a pallet or call.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::unpause())]
+        pub fn unpause(origin: OriginFor<T>, target: PauseTargetOf<T>) -> DispatchResult {
+            Self::ensure_breaker_origin(origin, &target)?;
+            ensure!(Paused::<T>::contains_key(&target), Error::<T>::NotPaused);
+
+            Paused::<T>::remove(&target);
+
+            Self::deposit_event(Event::Unpaused { target });
+
+            Ok(())
+        }
+
+        /// Replace the guardian account, or remove it with `None`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_guardian())]
+        pub fn set_guardian(origin: OriginFor<T>, guardian: Option<T::AccountId>) -> DispatchResult {
+            T::OverrideOrigin::ensure_origin(origin)?;
+
+            Guardian::<T>::set(guardian.clone());
+
+            Self::deposit_event(Event::GuardianSet { guardian });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Check the origin may change the pause of `target`, returning whether it is the override
+        /// origin. The guardian origin may not touch a pause set by the override origin.
+        fn ensure_breaker_origin(
+            origin: OriginFor<T>,
+            target: &PauseTargetOf<T>,
+        ) -> Result<bool, DispatchError> {
+            let Err(origin) = T::OverrideOrigin::try_origin(origin) else {
+                return Ok(true);
+            };
+            T::GuardianOrigin::ensure_origin(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(info) = Paused::<T>::get(target) {
+                ensure!(
+                    !(info.by_override && Self::is_active(&info, now)),
+                    Error::<T>::PausedByOverride
+                );
+            }
+            Ok(false)
+        }
+
+        /// Whether a pause still applies at `now`.
+        fn is_active(info: &PauseInfo<BlockNumberFor<T>>, now: BlockNumberFor<T>) -> bool {
+            match info.expires_at {
+                Some(expires_at) => now < expires_at,
+                None => true,
+            }
+        }
+
+        /// Whether the call `function` of `pallet` is currently paused, either on its own or
+        /// through its pallet.
+        pub fn is_paused(pallet: &str, function: &str) -> bool {
+            let (Ok(pallet), Ok(function)) = (
+                NameOf::<T>::try_from(pallet.as_bytes().to_vec()),
+                NameOf::<T>::try_from(function.as_bytes().to_vec()),
+            ) else {
+                // Names longer than `MaxNameLen` cannot have been paused.
+                return false;
+            };
+
+            let now = frame_system::Pallet::<T>::block_number();
+            [PauseTarget::Pallet(pallet.clone()), PauseTarget::Call(pallet, function)]
+                .iter()
+                .filter_map(Paused::<T>::get)
+                .any(|info| Self::is_active(&info, now))
+        }
+    }
+
+    /// Ensures the origin is signed by the [`Guardian`] account.
+    pub struct EnsureGuardian<T>(PhantomData<T>);
+
+    impl<T: Config> EnsureOrigin<OriginFor<T>> for EnsureGuardian<T> {
+        type Success = T::AccountId;
+
+        fn try_origin(origin: OriginFor<T>) -> Result<Self::Success, OriginFor<T>> {
+            origin.into().and_then(|raw| match raw {
+                frame_system::RawOrigin::Signed(who) if Guardian::<T>::get().as_ref() == Some(&who) => Ok(who),
+                raw => Err(raw.into()),
+            })
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn try_successful_origin() -> Result<OriginFor<T>, ()> {
+            Guardian::<T>::get().map(|who| frame_system::RawOrigin::Signed(who).into()).ok_or(())
+        }
+    }
+
+    /// Filters out paused calls when used as the runtime's `BaseCallFilter`.
+    impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for Pallet<T>
+    where
+        <T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+    {
+        fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+            let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+            !Self::is_paused(pallet_name, function_name)
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate as pallet_circuit_breaker;
+use frame_support::{
+    derive_impl,
+    traits::{ConstU32, ConstU64, Contains},
+};
+use frame_system::EnsureRoot;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type CircuitBreaker = pallet_circuit_breaker::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type BaseCallFilter = CircuitBreaker;
+}
+
+pub const GUARDIAN: u64 = 1;
+pub const ALICE: u64 = 2;
+pub const MAX_GUARDIAN_PAUSE: u64 = 10;
+
+/// Keeps the circuit breaker itself callable.
+pub struct WhitelistedPallets;
+
+impl Contains<pallet_circuit_breaker::NameOf<Test>> for WhitelistedPallets {
+    fn contains(pallet: &pallet_circuit_breaker::NameOf<Test>) -> bool {
+        pallet.as_slice() == b"CircuitBreaker"
+    }
+}
+
+impl pallet_circuit_breaker::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type GuardianOrigin = pallet_circuit_breaker::EnsureGuardian<Test>;
+    type OverrideOrigin = EnsureRoot<u64>;
+    type WhitelistedPallets = WhitelistedPallets;
+    type MaxGuardianPause = ConstU64<MAX_GUARDIAN_PAUSE>;
+    type MaxNameLen = ConstU32<32>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_circuit_breaker::GenesisConfig::<Test> { guardian: Some(GUARDIAN) }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
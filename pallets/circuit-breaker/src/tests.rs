@@ -0,0 +1,199 @@
+use crate::{mock::*, Error, Event, PauseInfo, PauseTarget, PauseTargetOf, Paused};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_runtime::{traits::Dispatchable, DispatchError};
+
+fn pallet(name: &str) -> PauseTargetOf<Test> {
+    PauseTarget::Pallet(name.as_bytes().to_vec().try_into().unwrap())
+}
+
+fn call(pallet: &str, function: &str) -> PauseTargetOf<Test> {
+    PauseTarget::Call(
+        pallet.as_bytes().to_vec().try_into().unwrap(),
+        function.as_bytes().to_vec().try_into().unwrap(),
+    )
+}
+
+// Dispatch a `System::remark` through the call filter.
+fn remark() -> Result<(), DispatchError> {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+        .dispatch(RuntimeOrigin::signed(ALICE))
+        .map(|_| ())
+        .map_err(|e| e.error)
+}
+
+fn remark_with_event() -> Result<(), DispatchError> {
+    RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] })
+        .dispatch(RuntimeOrigin::signed(ALICE))
+        .map(|_| ())
+        .map_err(|e| e.error)
+}
+
+#[test]
+fn guardian_pause_filters_call_until_expiry() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CircuitBreaker::pause(
+            RuntimeOrigin::signed(GUARDIAN),
+            call("System", "remark"),
+            Some(5)
+        ));
+        System::assert_last_event(
+            Event::Paused {
+                target: call("System", "remark"),
+                expires_at: Some(6),
+                by_override: false,
+            }
+            .into(),
+        );
+
+        assert_eq!(remark(), Err(frame_system::Error::<Test>::CallFiltered.into()));
+        assert_ok!(remark_with_event());
+
+        System::set_block_number(5);
+        assert!(remark().is_err());
+        System::set_block_number(6);
+        assert_ok!(remark());
+    });
+}
+
+#[test]
+fn pallet_pause_filters_every_call() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CircuitBreaker::pause(RuntimeOrigin::signed(GUARDIAN), pallet("System"), None));
+        assert_eq!(
+            Paused::<Test>::get(pallet("System")),
+            Some(PauseInfo { expires_at: Some(1 + MAX_GUARDIAN_PAUSE), by_override: false })
+        );
+
+        assert!(remark().is_err());
+        assert!(remark_with_event().is_err());
+
+        assert_ok!(CircuitBreaker::unpause(RuntimeOrigin::signed(GUARDIAN), pallet("System")));
+        System::assert_last_event(Event::Unpaused { target: pallet("System") }.into());
+        assert_ok!(remark());
+    });
+}
+
+#[test]
+fn guardian_pauses_are_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CircuitBreaker::pause(RuntimeOrigin::signed(ALICE), pallet("System"), Some(1)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CircuitBreaker::pause(
+                RuntimeOrigin::signed(GUARDIAN),
+                pallet("System"),
+                Some(MAX_GUARDIAN_PAUSE + 1)
+            ),
+            Error::<Test>::PauseTooLong
+        );
+        assert_noop!(
+            CircuitBreaker::pause(RuntimeOrigin::signed(GUARDIAN), pallet("System"), Some(0)),
+            Error::<Test>::InvalidDuration
+        );
+        assert_noop!(
+            CircuitBreaker::pause(
+                RuntimeOrigin::signed(GUARDIAN),
+                pallet("CircuitBreaker"),
+                Some(1)
+            ),
+            Error::<Test>::Unpausable
+        );
+        assert_noop!(
+            CircuitBreaker::unpause(RuntimeOrigin::signed(GUARDIAN), pallet("System")),
+            Error::<Test>::NotPaused
+        );
+    });
+}
+
+#[test]
+fn override_pause_binds_guardian() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CircuitBreaker::pause(RuntimeOrigin::root(), call("System", "remark"), None));
+        System::assert_last_event(
+            Event::Paused { target: call("System", "remark"), expires_at: None, by_override: true }
+                .into(),
+        );
+
+        assert_noop!(
+            CircuitBreaker::unpause(RuntimeOrigin::signed(GUARDIAN), call("System", "remark")),
+            Error::<Test>::PausedByOverride
+        );
+        assert_noop!(
+            CircuitBreaker::pause(
+                RuntimeOrigin::signed(GUARDIAN),
+                call("System", "remark"),
+                Some(1)
+            ),
+            Error::<Test>::PausedByOverride
+        );
+
+        System::set_block_number(1_000);
+        assert!(remark().is_err());
+
+        assert_ok!(CircuitBreaker::unpause(RuntimeOrigin::root(), call("System", "remark")));
+        assert_ok!(remark());
+    });
+}
+
+#[test]
+fn override_lifts_guardian_pause() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CircuitBreaker::pause(
+            RuntimeOrigin::signed(GUARDIAN),
+            pallet("System"),
+            Some(3)
+        ));
+        assert_ok!(CircuitBreaker::unpause(RuntimeOrigin::root(), pallet("System")));
+        assert_ok!(remark());
+    });
+}
+
+#[test]
+fn on_idle_prunes_expired_pauses() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CircuitBreaker::pause(
+            RuntimeOrigin::signed(GUARDIAN),
+            call("System", "remark"),
+            Some(2)
+        ));
+        assert_ok!(CircuitBreaker::pause(
+            RuntimeOrigin::signed(GUARDIAN),
+            pallet("System"),
+            Some(5)
+        ));
+
+        CircuitBreaker::on_idle(3, Weight::MAX);
+        System::assert_last_event(Event::PauseExpired { target: call("System", "remark") }.into());
+        assert!(!Paused::<Test>::contains_key(call("System", "remark")));
+        assert!(Paused::<Test>::contains_key(pallet("System")));
+
+        assert_eq!(CircuitBreaker::on_idle(3, Weight::zero()), Weight::zero());
+    });
+}
+
+#[test]
+fn override_replaces_the_guardian() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(CircuitBreaker::guardian(), Some(GUARDIAN));
+        assert_noop!(
+            CircuitBreaker::set_guardian(RuntimeOrigin::signed(GUARDIAN), Some(ALICE)),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(CircuitBreaker::set_guardian(RuntimeOrigin::root(), Some(ALICE)));
+        System::assert_last_event(Event::GuardianSet { guardian: Some(ALICE) }.into());
+        assert_noop!(
+            CircuitBreaker::pause(RuntimeOrigin::signed(GUARDIAN), pallet("System"), Some(1)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(CircuitBreaker::pause(RuntimeOrigin::signed(ALICE), pallet("System"), Some(1)));
+
+        assert_ok!(CircuitBreaker::set_guardian(RuntimeOrigin::root(), None));
+        assert_noop!(
+            CircuitBreaker::unpause(RuntimeOrigin::signed(ALICE), pallet("System")),
+            DispatchError::BadOrigin
+        );
+    });
+}
@@ -0,0 +1,202 @@
+//! `fungible` traits for the CREATE ledger.
+//!
+//! These let other pallets, such as the DEX, the DAO and the fee engine, inspect and move CREATE
+//! through a standard interface instead of the pallet's own calls. The rules of the calls still
+//! apply: minting stays within [`Config::MaxSupply`], no account subject to the wallet cap can
+//! receive CREATE beyond it, and vesting CREATE cannot be withdrawn or put on hold, not even by
+//! force. Staked, unbonding and held CREATE count towards the total balance of an account but
+//! cannot be withdrawn either.
+//!
+//! [`Unbalanced`] applies the wallet cap to every balance increase and never sets the total supply
+//! above [`Config::MaxSupply`].
+//!
+//! Unlike `mint_tokens`, [`Mutate::mint_into`] takes no origin: it is meant for pallets the
+//! runtime trusts to mint in place of [`Config::MintOrigin`].
+
+use crate::{Balances, BalanceOf, Config, Event, Holds, Pallet, StakedBalances, TotalHeld, TotalSupply};
+use frame_support::traits::{
+    fungible::{hold, Dust, Inspect, Mutate, Unbalanced},
+    tokens::{DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence},
+    Get,
+};
+use sp_runtime::{
+    traits::{CheckedAdd, CheckedSub, Saturating, Zero},
+    DispatchError,
+};
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+    type Balance = BalanceOf<T>;
+
+    fn total_issuance() -> Self::Balance {
+        TotalSupply::<T>::get()
+    }
+
+    fn minimum_balance() -> Self::Balance {
+        Zero::zero()
+    }
+
+    fn total_balance(who: &T::AccountId) -> Self::Balance {
+        Balances::<T>::get(who)
+            .saturating_add(StakedBalances::<T>::get(who))
+            .saturating_add(Self::unbonding_balance(who))
+            .saturating_add(TotalHeld::<T>::get(who))
+    }
+
+    fn balance(who: &T::AccountId) -> Self::Balance {
+        Balances::<T>::get(who)
+    }
+
+    fn reducible_balance(who: &T::AccountId, _preservation: Preservation, _force: Fortitude) -> Self::Balance {
+        // Vesting CREATE stays locked even when forced
+        Self::transferable_balance(who)
+    }
+
+    fn can_deposit(who: &T::AccountId, amount: Self::Balance, provenance: Provenance) -> DepositConsequence {
+        if amount.is_zero() {
+            return DepositConsequence::Success;
+        }
+
+        if provenance == Provenance::Minted {
+            match TotalSupply::<T>::get().checked_add(&amount) {
+                Some(total_supply) if total_supply <= T::MaxSupply::get() => {},
+                _ => return DepositConsequence::Overflow,
+            }
+        }
+
+        let Some(new_balance) = Balances::<T>::get(who).checked_add(&amount) else {
+            return DepositConsequence::Overflow;
+        };
+        if Self::ensure_within_wallet_cap(who, new_balance).is_err() {
+            return DepositConsequence::Blocked;
+        }
+
+        DepositConsequence::Success
+    }
+
+    fn can_withdraw(who: &T::AccountId, amount: Self::Balance) -> WithdrawConsequence<Self::Balance> {
+        if amount.is_zero() {
+            return WithdrawConsequence::Success;
+        }
+
+        if TotalSupply::<T>::get().checked_sub(&amount).is_none() {
+            return WithdrawConsequence::Underflow;
+        }
+        if Balances::<T>::get(who) < amount {
+            return WithdrawConsequence::BalanceLow;
+        }
+        if Self::transferable_balance(who) < amount {
+            return WithdrawConsequence::Frozen;
+        }
+
+        WithdrawConsequence::Success
+    }
+}
+
+impl<T: Config> Unbalanced<T::AccountId> for Pallet<T> {
+    fn handle_dust(_dust: Dust<T::AccountId, Self>) {
+        // The minimum balance is zero, so there is never any dust.
+    }
+
+    fn write_balance(who: &T::AccountId, amount: Self::Balance) -> Result<Option<Self::Balance>, DispatchError> {
+        // Increases are deposits, so `increase_balance` cannot lift an account over the wallet cap
+        let balance = Balances::<T>::get(who);
+        if amount > balance {
+            Self::can_deposit(who, amount.saturating_sub(balance), Provenance::Extant).into_result()?;
+        }
+        Balances::<T>::insert(who, amount);
+        Ok(None)
+    }
+
+    fn set_total_issuance(amount: Self::Balance) {
+        TotalSupply::<T>::put(amount.min(T::MaxSupply::get()));
+    }
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+    fn mint_into(who: &T::AccountId, amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+        Self::do_mint(who, amount)?;
+        Self::done_mint_into(who, amount);
+        Ok(amount)
+    }
+
+    fn done_mint_into(who: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::TokensMinted { account: who.clone(), amount });
+    }
+
+    fn done_burn_from(who: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::TokensBurned { account: who.clone(), amount });
+    }
+
+    fn done_transfer(source: &T::AccountId, dest: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::TokensTransferred { from: source.clone(), to: dest.clone(), amount });
+    }
+}
+
+impl<T: Config> hold::Inspect<T::AccountId> for Pallet<T> {
+    type Reason = T::RuntimeHoldReason;
+
+    fn total_balance_on_hold(who: &T::AccountId) -> Self::Balance {
+        TotalHeld::<T>::get(who)
+    }
+
+    fn balance_on_hold(reason: &Self::Reason, who: &T::AccountId) -> Self::Balance {
+        Holds::<T>::get(who, reason)
+    }
+}
+
+impl<T: Config> hold::Unbalanced<T::AccountId> for Pallet<T> {
+    fn set_balance_on_hold(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) -> Result<(), DispatchError> {
+        let old_amount = Holds::<T>::get(who, reason);
+        let total_held = TotalHeld::<T>::get(who).saturating_sub(old_amount).saturating_add(amount);
+
+        if amount.is_zero() {
+            Holds::<T>::remove(who, reason);
+        } else {
+            Holds::<T>::insert(who, reason, amount);
+        }
+        if total_held.is_zero() {
+            TotalHeld::<T>::remove(who);
+        } else {
+            TotalHeld::<T>::insert(who, total_held);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config> hold::Mutate<T::AccountId> for Pallet<T> {
+    fn done_hold(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::TokensHeld { reason: reason.clone(), account: who.clone(), amount });
+    }
+
+    fn done_release(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::TokensReleased { reason: reason.clone(), account: who.clone(), amount });
+    }
+
+    fn done_burn_held(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::HeldTokensBurned { reason: reason.clone(), account: who.clone(), amount });
+    }
+
+    fn done_transfer_on_hold(
+        reason: &Self::Reason,
+        source: &T::AccountId,
+        dest: &T::AccountId,
+        amount: Self::Balance,
+    ) {
+        Self::deposit_event(Event::HeldTokensTransferred {
+            reason: reason.clone(),
+            from: source.clone(),
+            to: dest.clone(),
+            amount,
+        });
+    }
+
+    fn done_transfer_and_hold(
+        reason: &Self::Reason,
+        source: &T::AccountId,
+        dest: &T::AccountId,
+        transferred: Self::Balance,
+    ) {
+        Self::deposit_event(Event::TokensTransferred { from: source.clone(), to: dest.clone(), amount: transferred });
+        Self::done_hold(reason, dest, transferred);
+    }
+}
@@ -2,6 +2,30 @@
 //!
 //! This pallet implements the CREATE governance token for the CREATEFI blockchain.
 //! CREATE is the native DAO token with governance rights, staking rewards, and anti-whale protection.
+//!
+//! The initial supply is created at genesis. Part of it is allocated to accounts there, and the
+//! rest is allocated later by [`Config::MintOrigin`]. Only that origin can emit new CREATE, and
+//! never beyond [`Config::MaxSupply`].
+//!
+//! Staking rewards are emitted at a governance-set rate per block from a reward pool funded with
+//! CREATE, and shared pro rata among stakers through a reward-per-share accumulator. Emission
+//! stops when the pool runs dry, so rewards are never minted.
+//!
+//! Unstaked CREATE stops earning rewards and voting at once, but is queued for
+//! [`Config::UnbondingPeriod`] blocks before `withdraw_unbonded` returns it. Until then it can be
+//! staked again with `rebond`. The staking lock on [`Config::Currency`] only covers staked CREATE.
+//!
+//! Allocations can vest linearly per block after a cliff. Vesting CREATE cannot be transferred or
+//! burned, but can be staked. The vesting lock only shrinks when `vest` is called.
+//!
+//! No account may receive CREATE beyond [`Config::MaxWalletPercentage`] of either the total or the
+//! circulating supply, as chosen by governance. Circulating supply leaves out unallocated, pooled,
+//! staked and unbonding CREATE and the balances of exempt accounts such as the DAO treasury and
+//! DEX pools, which the cap does not apply to. The cap only applies once the measured supply
+//! reaches [`Config::WalletCapBootstrapSupply`], so the first mint is not blocked.
+//!
+//! Other pallets can hold and move CREATE through the `fungible` traits implemented in
+//! [`fungible`], subject to the same supply cap, wallet cap and vesting lock.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -11,18 +35,32 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod fungible;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use codec::DecodeWithMemTracking;
+    use serde::{Deserialize, Serialize};
     use frame_support::{
         pallet_prelude::*,
         traits::{Currency, WithdrawReasons, LockableCurrency, LockIdentifier},
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero, Saturating};
+    use sp_runtime::{
+        traits::{CheckedAdd, CheckedSub, SaturatedConversion, Zero, Saturating},
+        FixedPointNumber, FixedU128,
+    };
+    use sp_std::vec::Vec;
 
     /// The pallet's configuration trait.
     #[pallet::config]
@@ -35,6 +73,13 @@ pub mod pallet {
         
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
+
+        /// The origin allowed to emit new CREATE and allocate the initial supply.
+        type MintOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The hard cap on the total supply of CREATE.
+        #[pallet::constant]
+        type MaxSupply: Get<BalanceOf<Self>>;
         
         /// The maximum percentage any single wallet can hold (5%).
         #[pallet::constant]
@@ -51,13 +96,90 @@ pub mod pallet {
         /// The lock identifier for governance voting.
         #[pallet::constant]
         type GovernanceLockId: Get<LockIdentifier>;
+
+        /// The origin allowed to set the staking reward emission rate.
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The number of blocks unstaked CREATE stays locked before it can be withdrawn.
+        #[pallet::constant]
+        type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of unbonding chunks per account.
+        #[pallet::constant]
+        type MaxUnlockChunks: Get<u32>;
+
+        /// The maximum number of vesting schedules per account.
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+
+        /// The maximum number of accounts exempt from the wallet cap.
+        #[pallet::constant]
+        type MaxWalletCapExemptions: Get<u32>;
+
+        /// The supply, as measured for the wallet cap, below which the cap does not apply.
+        #[pallet::constant]
+        type WalletCapBootstrapSupply: Get<BalanceOf<Self>>;
+
+        /// The overarching hold reason, under which other pallets hold CREATE through the
+        /// `fungible` hold traits.
+        type RuntimeHoldReason: Parameter + Member + MaxEncodedLen;
+    }
+
+    /// The supply the wallet cap is a percentage of.
+    #[derive(Clone, Copy, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default, Serialize, Deserialize)]
+    pub enum WalletCapBasis {
+        /// All CREATE in existence.
+        #[default]
+        TotalSupply,
+        /// CREATE held freely by accounts subject to the cap.
+        CirculatingSupply,
     }
 
+    /// CREATE being unbonded, withdrawable from `unlock_at`.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct UnlockChunk<Balance, BlockNumber> {
+        /// Amount being unbonded.
+        pub value: Balance,
+        /// First block at which the amount can be withdrawn.
+        pub unlock_at: BlockNumber,
+    }
+
+    pub type UnlockChunksOf<T> = BoundedVec<UnlockChunk<BalanceOf<T>, BlockNumberFor<T>>, <T as Config>::MaxUnlockChunks>;
+
+    /// CREATE unlocking linearly from `starting_block`, with nothing unlocked before `cliff`.
+    #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct VestingSchedule<Balance, BlockNumber> {
+        /// Amount locked when the schedule was created.
+        pub locked: Balance,
+        /// Amount unlocked per block after `starting_block`.
+        pub per_block: Balance,
+        /// Block from which the amount unlocks.
+        pub starting_block: BlockNumber,
+        /// First block at which anything is unlocked; the amount vested by then unlocks at once.
+        pub cliff: BlockNumber,
+    }
+
+    pub type VestingScheduleOf<T> = VestingSchedule<BalanceOf<T>, BlockNumberFor<T>>;
+
+    /// A genesis vesting schedule: account, starting block, cliff, per-block unlock and locked
+    /// amount.
+    pub type GenesisVestingOf<T> = (
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        BlockNumberFor<T>,
+        BalanceOf<T>,
+        BalanceOf<T>,
+    );
+
     /// Balance type for this pallet.
     pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     /// The pallet's storage items.
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Total supply of CREATE tokens.
@@ -65,6 +187,11 @@ pub mod pallet {
     #[pallet::getter(fn total_supply)]
     pub type TotalSupply<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// Part of the initial supply not yet allocated to any account.
+    #[pallet::storage]
+    #[pallet::getter(fn unallocated_supply)]
+    pub type UnallocatedSupply<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
     /// CREATE balance for each account.
     #[pallet::storage]
     #[pallet::getter(fn balance_of)]
@@ -90,30 +217,145 @@ pub mod pallet {
     #[pallet::getter(fn total_governance_power)]
     pub type TotalGovernancePower<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-    /// Staking rewards rate (per block).
+    /// CREATE emitted to all stakers per block, while the reward pool lasts.
+    #[pallet::storage]
+    #[pallet::getter(fn reward_per_block)]
+    pub type RewardPerBlock<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// CREATE funded for staking rewards and not yet emitted.
+    #[pallet::storage]
+    #[pallet::getter(fn reward_pool)]
+    pub type RewardPool<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Rewards emitted per staked CREATE since genesis.
+    #[pallet::storage]
+    #[pallet::getter(fn reward_per_share)]
+    pub type RewardPerShare<T> = StorageValue<_, FixedU128, ValueQuery>;
+
+    /// The value of [`RewardPerShare`] when each account's rewards were last settled.
     #[pallet::storage]
-    #[pallet::getter(fn staking_reward_rate)]
-    pub type StakingRewardRate<T> = StorageValue<_, u32, ValueQuery>;
+    pub type RewardPerSharePaid<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, FixedU128, ValueQuery>;
 
-    /// Last reward distribution block.
+    /// Block up to which rewards have been emitted.
     #[pallet::storage]
-    pub type LastRewardBlock<T> = StorageValue<_, u32, ValueQuery>;
+    pub type LastRewardBlock<T> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
     /// Pending rewards for each account.
     #[pallet::storage]
     #[pallet::getter(fn pending_rewards)]
     pub type PendingRewards<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, BalanceOf<T>, ValueQuery>;
 
+    /// CREATE being unbonded by each account, oldest first.
+    #[pallet::storage]
+    #[pallet::getter(fn unlock_chunks)]
+    pub type UnlockChunks<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, UnlockChunksOf<T>, ValueQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// CREATE in existence at launch.
+        pub initial_supply: BalanceOf<T>,
+        /// Parts of the initial supply credited to accounts at launch.
+        pub allocations: Vec<(T::AccountId, BalanceOf<T>)>,
+        /// Vesting of allocated CREATE: account, starting block, cliff, per-block unlock and
+        /// locked amount.
+        pub vesting: Vec<GenesisVestingOf<T>>,
+        /// Accounts exempt from the wallet cap, such as the DAO treasury and DEX pools.
+        pub wallet_cap_exemptions: Vec<T::AccountId>,
+        /// The supply the wallet cap is measured against.
+        pub wallet_cap_basis: WalletCapBasis,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            assert!(self.initial_supply <= T::MaxSupply::get(), "initial supply exceeds the maximum supply");
+
+            let mut unallocated = self.initial_supply;
+            for (account, amount) in &self.allocations {
+                unallocated = unallocated.checked_sub(amount)
+                    .expect("genesis allocations exceed the initial supply");
+                Balances::<T>::mutate(account, |balance| *balance = balance.saturating_add(*amount));
+            }
+
+            TotalSupply::<T>::put(self.initial_supply);
+            UnallocatedSupply::<T>::put(unallocated);
+
+            for (account, starting_block, cliff, per_block, locked) in &self.vesting {
+                let schedule = VestingSchedule {
+                    locked: *locked,
+                    per_block: *per_block,
+                    starting_block: *starting_block,
+                    cliff: *cliff,
+                };
+                Pallet::<T>::ensure_valid_schedule(&schedule).expect("genesis vesting schedules should be valid");
+                Vesting::<T>::try_append(account, schedule)
+                    .expect("genesis vesting schedules should fit the bound");
+                let vesting_locked = VestingLocked::<T>::get(account).saturating_add(*locked);
+                assert!(
+                    vesting_locked <= Balances::<T>::get(account),
+                    "genesis vesting exceeds the allocated balance"
+                );
+                VestingLocked::<T>::insert(account, vesting_locked);
+            }
+
+            let exemptions: BoundedVec<_, T::MaxWalletCapExemptions> = self.wallet_cap_exemptions.clone()
+                .try_into()
+                .expect("genesis wallet cap exemptions should fit the bound");
+            WalletCapExemptions::<T>::put(exemptions);
+            WalletCapBasisOf::<T>::put(self.wallet_cap_basis);
+        }
+    }
+
+    /// Vesting schedules of each account.
+    #[pallet::storage]
+    #[pallet::getter(fn vesting)]
+    pub type Vesting<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, BoundedVec<VestingScheduleOf<T>, <T as Config>::MaxVestingSchedules>, ValueQuery>;
+
+    /// CREATE of each account locked by vesting as of its last `vest`.
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_locked)]
+    pub type VestingLocked<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Total CREATE unbonding or waiting to be withdrawn.
+    #[pallet::storage]
+    #[pallet::getter(fn total_unbonding)]
+    pub type TotalUnbonding<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Accounts the wallet cap does not apply to.
+    #[pallet::storage]
+    #[pallet::getter(fn wallet_cap_exemptions)]
+    pub type WalletCapExemptions<T> = StorageValue<_, BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxWalletCapExemptions>, ValueQuery>;
+
+    /// The supply the wallet cap is measured against.
+    #[pallet::storage]
+    #[pallet::getter(fn wallet_cap_basis)]
+    pub type WalletCapBasisOf<T> = StorageValue<_, WalletCapBasis, ValueQuery>;
+
+    /// CREATE of each account held by other pallets, per hold reason.
+    #[pallet::storage]
+    pub type Holds<T> = StorageDoubleMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Blake2_128Concat, <T as Config>::RuntimeHoldReason, BalanceOf<T>, ValueQuery>;
+
+    /// CREATE of each account held by other pallets, for any reason.
+    #[pallet::storage]
+    pub type TotalHeld<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, BalanceOf<T>, ValueQuery>;
+
     /// Events that functions in this pallet can emit.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// CREATE tokens were minted.
+        /// New CREATE tokens were emitted, increasing the total supply.
         /// [account, amount]
         TokensMinted {
             account: T::AccountId,
             amount: BalanceOf<T>,
         },
+        /// CREATE tokens of the initial supply were allocated.
+        /// [account, amount]
+        TokensAllocated {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
         /// CREATE tokens were burned.
         /// [account, amount]
         TokensBurned {
@@ -133,11 +375,56 @@ pub mod pallet {
             account: T::AccountId,
             amount: BalanceOf<T>,
         },
-        /// CREATE tokens were unstaked.
-        /// [account, amount]
+        /// CREATE tokens were unstaked and started unbonding.
+        /// [account, amount, unlock_at]
         TokensUnstaked {
             account: T::AccountId,
             amount: BalanceOf<T>,
+            unlock_at: BlockNumberFor<T>,
+        },
+        /// A vesting schedule was added to an account.
+        /// [account, schedule]
+        VestingScheduleAdded {
+            account: T::AccountId,
+            schedule: VestingScheduleOf<T>,
+        },
+        /// Vested CREATE tokens were unlocked.
+        /// [account, unvested]
+        VestingUpdated {
+            account: T::AccountId,
+            unvested: BalanceOf<T>,
+        },
+        /// All vesting schedules of an account finished.
+        /// [account]
+        VestingCompleted {
+            account: T::AccountId,
+        },
+        /// An account was exempted from the wallet cap.
+        /// [account]
+        WalletCapExemptionAdded {
+            account: T::AccountId,
+        },
+        /// An account is subject to the wallet cap again.
+        /// [account]
+        WalletCapExemptionRemoved {
+            account: T::AccountId,
+        },
+        /// The supply the wallet cap is measured against changed.
+        /// [basis]
+        WalletCapBasisSet {
+            basis: WalletCapBasis,
+        },
+        /// Unbonded CREATE tokens were withdrawn.
+        /// [account, amount]
+        UnbondedWithdrawn {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Unbonding CREATE tokens were staked again.
+        /// [account, amount]
+        TokensRebonded {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
         },
         /// Staking rewards were claimed.
         /// [account, amount]
@@ -145,12 +432,52 @@ pub mod pallet {
             account: T::AccountId,
             amount: BalanceOf<T>,
         },
+        /// The reward pool was funded.
+        /// [from, amount]
+        RewardPoolFunded {
+            from: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The staking reward emission rate was changed.
+        /// [reward_per_block]
+        RewardRateUpdated {
+            reward_per_block: BalanceOf<T>,
+        },
         /// Governance power was updated.
         /// [account, new_power]
         GovernancePowerUpdated {
             account: T::AccountId,
             new_power: BalanceOf<T>,
         },
+        /// CREATE tokens were put on hold.
+        /// [reason, account, amount]
+        TokensHeld {
+            reason: T::RuntimeHoldReason,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Held CREATE tokens were released.
+        /// [reason, account, amount]
+        TokensReleased {
+            reason: T::RuntimeHoldReason,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Held CREATE tokens were burned.
+        /// [reason, account, amount]
+        HeldTokensBurned {
+            reason: T::RuntimeHoldReason,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Held CREATE tokens were transferred.
+        /// [reason, from, to, amount]
+        HeldTokensTransferred {
+            reason: T::RuntimeHoldReason,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     /// Errors that can be returned by this pallet.
@@ -162,6 +489,24 @@ pub mod pallet {
         AmountExceedsMaximum,
         /// Amount is below minimum required.
         AmountBelowMinimum,
+        /// Minting would exceed the maximum supply.
+        ExceedsMaxSupply,
+        /// Not enough of the initial supply is left to allocate.
+        InsufficientUnallocatedSupply,
+        /// The amount is still locked by vesting.
+        VestingLocked,
+        /// The account has no vesting schedules.
+        NotVesting,
+        /// The account has too many vesting schedules.
+        TooManyVestingSchedules,
+        /// The vesting schedule locks nothing, unlocks nothing, or has its cliff before its start.
+        InvalidVestingSchedule,
+        /// The account is already exempt from the wallet cap.
+        AlreadyWalletCapExempt,
+        /// The account is not exempt from the wallet cap.
+        NotWalletCapExempt,
+        /// Too many accounts are exempt from the wallet cap.
+        TooManyWalletCapExemptions,
         /// Operation would cause overflow.
         Overflow,
         /// Operation would cause underflow.
@@ -178,44 +523,29 @@ pub mod pallet {
         StakingLockActive,
         /// Governance lock is active.
         GovernanceLockActive,
+        /// The account has too many unbonding chunks; withdraw or rebond first.
+        TooManyUnlockChunks,
+        /// No unbonded tokens are ready to withdraw.
+        NoUnbondedTokens,
+        /// Insufficient unbonding balance.
+        InsufficientUnbondingBalance,
+        /// Tokens cannot be transferred to the sending account.
+        TransferToSelf,
     }
 
     /// The pallet's dispatchable functions.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Mint CREATE tokens (only callable by authorized accounts).
+        /// Emit new CREATE tokens, up to the maximum supply.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::mint_tokens())]
         pub fn mint_tokens(origin: OriginFor<T>, to: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-            
-            // TODO: Add authorization check for minting
-            // For now, allow any signed account to mint (should be restricted in production)
+            T::MintOrigin::ensure_origin(origin)?;
 
             // Ensure amount is not zero
             ensure!(!amount.is_zero(), Error::<T>::AmountBelowMinimum);
 
-            // Check if transfer would exceed max wallet percentage
-            let current_balance = Balances::<T>::get(&to);
-            let new_balance = current_balance.checked_add(&amount)
-                .ok_or(Error::<T>::Overflow)?;
-            
-            let total_supply = TotalSupply::<T>::get();
-            let max_allowed = total_supply
-                .checked_mul(&T::MaxWalletPercentage::get().into())
-                .ok_or(Error::<T>::Overflow)?
-                .checked_div(&100u32.into())
-                .ok_or(Error::<T>::Underflow)?;
-            
-            ensure!(new_balance <= max_allowed, Error::<T>::ExceedsMaxWalletPercentage);
-
-            // Mint tokens
-            Balances::<T>::insert(&to, new_balance);
-            
-            let total_supply = TotalSupply::<T>::get()
-                .checked_add(&amount)
-                .ok_or(Error::<T>::Overflow)?;
-            TotalSupply::<T>::put(total_supply);
+            Self::do_mint(&to, amount)?;
 
             Self::deposit_event(Event::TokensMinted {
                 account: to,
@@ -237,6 +567,7 @@ pub mod pallet {
             // Check if user has sufficient balance
             let current_balance = Balances::<T>::get(&who);
             ensure!(current_balance >= amount, Error::<T>::InsufficientBalance);
+            Self::ensure_unlocked(&who, amount)?;
 
             // Burn tokens
             let new_balance = current_balance.checked_sub(&amount)
@@ -265,6 +596,7 @@ pub mod pallet {
             amount: BalanceOf<T>,
         ) -> DispatchResult {
             let from = ensure_signed(origin)?;
+            ensure!(from != to, Error::<T>::TransferToSelf);
 
             // Ensure amount is not zero
             ensure!(!amount.is_zero(), Error::<T>::AmountBelowMinimum);
@@ -272,20 +604,13 @@ pub mod pallet {
             // Check if sender has sufficient balance
             let from_balance = Balances::<T>::get(&from);
             ensure!(from_balance >= amount, Error::<T>::InsufficientBalance);
+            Self::ensure_unlocked(&from, amount)?;
 
             // Check if recipient would exceed max wallet percentage
             let to_balance = Balances::<T>::get(&to);
             let new_to_balance = to_balance.checked_add(&amount)
                 .ok_or(Error::<T>::Overflow)?;
-            
-            let total_supply = TotalSupply::<T>::get();
-            let max_allowed = total_supply
-                .checked_mul(&T::MaxWalletPercentage::get().into())
-                .ok_or(Error::<T>::Overflow)?
-                .checked_div(&100u32.into())
-                .ok_or(Error::<T>::Underflow)?;
-            
-            ensure!(new_to_balance <= max_allowed, Error::<T>::ExceedsMaxWalletPercentage);
+            Self::ensure_within_wallet_cap(&to, new_to_balance)?;
 
             // Transfer tokens
             let new_from_balance = from_balance.checked_sub(&amount)
@@ -315,6 +640,9 @@ pub mod pallet {
             let current_balance = Balances::<T>::get(&who);
             ensure!(current_balance >= amount, Error::<T>::InsufficientBalance);
 
+            // Settle rewards earned on the previous stake
+            Self::settle_rewards(&who);
+
             // Update balances
             let new_balance = current_balance.checked_sub(&amount)
                 .ok_or(Error::<T>::Underflow)?;
@@ -335,12 +663,7 @@ pub mod pallet {
             Self::update_governance_power(&who, new_staked_balance)?;
 
             // Lock tokens for staking
-            T::Currency::set_lock(
-                T::StakingLockId::get(),
-                &who,
-                new_staked_balance,
-                WithdrawReasons::all(),
-            );
+            Self::update_stake_lock(&who);
 
             Self::deposit_event(Event::TokensStaked {
                 account: who,
@@ -363,36 +686,46 @@ pub mod pallet {
             let staked_balance = StakedBalances::<T>::get(&who);
             ensure!(staked_balance >= amount, Error::<T>::InsufficientStakedBalance);
 
+            // Settle rewards earned on the previous stake
+            Self::settle_rewards(&who);
+
             // Update staked balances
             let new_staked_balance = staked_balance.checked_sub(&amount)
                 .ok_or(Error::<T>::Underflow)?;
             StakedBalances::<T>::insert(&who, new_staked_balance);
 
-            let current_balance = Balances::<T>::get(&who);
-            let new_balance = current_balance.checked_add(&amount)
-                .ok_or(Error::<T>::Overflow)?;
-            Balances::<T>::insert(&who, new_balance);
+            // Queue the tokens for withdrawal after the unbonding period
+            let unlock_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::UnbondingPeriod::get());
+            UnlockChunks::<T>::try_mutate(&who, |chunks| -> DispatchResult {
+                match chunks.last_mut() {
+                    Some(last) if last.unlock_at == unlock_at => {
+                        last.value = last.value.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+                    }
+                    _ => chunks
+                        .try_push(UnlockChunk { value: amount, unlock_at })
+                        .map_err(|_| Error::<T>::TooManyUnlockChunks)?,
+                }
+                Ok(())
+            })?;
 
             // Update total staked
             let total_staked = TotalStaked::<T>::get()
                 .checked_sub(&amount)
                 .ok_or(Error::<T>::Underflow)?;
             TotalStaked::<T>::put(total_staked);
+            TotalUnbonding::<T>::mutate(|total| *total = total.saturating_add(amount));
 
             // Update governance power
             Self::update_governance_power(&who, new_staked_balance)?;
 
-            // Update lock
-            T::Currency::set_lock(
-                T::StakingLockId::get(),
-                &who,
-                new_staked_balance,
-                WithdrawReasons::all(),
-            );
+            // Only the remaining stake stays locked
+            Self::update_stake_lock(&who);
 
             Self::deposit_event(Event::TokensUnstaked {
                 account: who,
                 amount,
+                unlock_at,
             });
 
             Ok(())
@@ -404,8 +737,8 @@ pub mod pallet {
         pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
-            // Calculate and distribute rewards
-            Self::distribute_rewards(&who)?;
+            // Settle rewards earned so far
+            Self::settle_rewards(&who);
 
             let pending_rewards = PendingRewards::<T>::get(&who);
             ensure!(!pending_rewards.is_zero(), Error::<T>::NoRewardsAvailable);
@@ -426,6 +759,283 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Move CREATE into the staking reward pool.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::fund_reward_pool())]
+        pub fn fund_reward_pool(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Ensure amount is not zero
+            ensure!(!amount.is_zero(), Error::<T>::AmountBelowMinimum);
+
+            let current_balance = Balances::<T>::get(&who);
+            let new_balance = current_balance.checked_sub(&amount)
+                .ok_or(Error::<T>::InsufficientBalance)?;
+            Self::ensure_unlocked(&who, amount)?;
+
+            // Emit at the current pool size before topping it up
+            Self::accrue_rewards();
+            let new_pool = RewardPool::<T>::get().checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+
+            Balances::<T>::insert(&who, new_balance);
+            RewardPool::<T>::put(new_pool);
+
+            Self::deposit_event(Event::RewardPoolFunded {
+                from: who,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Set the CREATE emitted to all stakers per block.
+        ///
+        /// Rewards up to the current block are emitted at the previous rate.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_reward_per_block())]
+        pub fn set_reward_per_block(origin: OriginFor<T>, reward_per_block: BalanceOf<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            Self::accrue_rewards();
+            RewardPerBlock::<T>::put(reward_per_block);
+
+            Self::deposit_event(Event::RewardRateUpdated { reward_per_block });
+
+            Ok(())
+        }
+
+        /// Allocate CREATE from the part of the initial supply left unallocated at genesis.
+        ///
+        /// Allocations do not change the total supply and are not subject to the maximum wallet
+        /// percentage, so that treasuries and vesting accounts can be funded.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::allocate_tokens())]
+        pub fn allocate_tokens(origin: OriginFor<T>, to: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            T::MintOrigin::ensure_origin(origin)?;
+
+            // Ensure amount is not zero
+            ensure!(!amount.is_zero(), Error::<T>::AmountBelowMinimum);
+
+            let unallocated = UnallocatedSupply::<T>::get().checked_sub(&amount)
+                .ok_or(Error::<T>::InsufficientUnallocatedSupply)?;
+            let new_balance = Balances::<T>::get(&to).checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+
+            UnallocatedSupply::<T>::put(unallocated);
+            Balances::<T>::insert(&to, new_balance);
+
+            Self::deposit_event(Event::TokensAllocated {
+                account: to,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Unlock the CREATE that has vested so far.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::vest(T::MaxVestingSchedules::get()))]
+        pub fn vest(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Vesting::<T>::contains_key(&who), Error::<T>::NotVesting);
+            Self::update_vesting(&who);
+
+            Ok(())
+        }
+
+        /// Transfer CREATE that vests for the recipient according to `schedule`.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::vested_transfer(T::MaxVestingSchedules::get()))]
+        pub fn vested_transfer(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            schedule: VestingScheduleOf<T>,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            ensure!(from != to, Error::<T>::TransferToSelf);
+
+            Self::ensure_valid_schedule(&schedule)?;
+            let amount = schedule.locked;
+
+            // Check if sender has sufficient unlocked balance
+            let from_balance = Balances::<T>::get(&from);
+            ensure!(from_balance >= amount, Error::<T>::InsufficientBalance);
+            Self::ensure_unlocked(&from, amount)?;
+
+            // Check if recipient would exceed max wallet percentage
+            let new_to_balance = Balances::<T>::get(&to).checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            Self::ensure_within_wallet_cap(&to, new_to_balance)?;
+
+            Vesting::<T>::try_mutate(&to, |schedules| schedules.try_push(schedule.clone()))
+                .map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+            let vesting_locked = VestingLocked::<T>::get(&to).checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+
+            // Transfer tokens
+            Balances::<T>::insert(&from, from_balance.saturating_sub(amount));
+            Balances::<T>::insert(&to, new_to_balance);
+            VestingLocked::<T>::insert(&to, vesting_locked);
+
+            Self::deposit_event(Event::TokensTransferred {
+                from,
+                to: to.clone(),
+                amount,
+            });
+            Self::deposit_event(Event::VestingScheduleAdded {
+                account: to,
+                schedule,
+            });
+
+            Ok(())
+        }
+
+        /// Exempt an account, such as a treasury, pool or bridge escrow, from the wallet cap.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::add_wallet_cap_exemption())]
+        pub fn add_wallet_cap_exemption(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            WalletCapExemptions::<T>::try_mutate(|exemptions| -> DispatchResult {
+                ensure!(!exemptions.contains(&account), Error::<T>::AlreadyWalletCapExempt);
+                exemptions.try_push(account.clone()).map_err(|_| Error::<T>::TooManyWalletCapExemptions)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::WalletCapExemptionAdded { account });
+
+            Ok(())
+        }
+
+        /// Apply the wallet cap to a previously exempt account again. Its existing balance is
+        /// kept, but it cannot receive more while above the cap.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::remove_wallet_cap_exemption())]
+        pub fn remove_wallet_cap_exemption(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            WalletCapExemptions::<T>::try_mutate(|exemptions| -> DispatchResult {
+                let position = exemptions.iter().position(|exempt| exempt == &account)
+                    .ok_or(Error::<T>::NotWalletCapExempt)?;
+                exemptions.remove(position);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::WalletCapExemptionRemoved { account });
+
+            Ok(())
+        }
+
+        /// Set the supply the wallet cap is measured against.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::set_wallet_cap_basis())]
+        pub fn set_wallet_cap_basis(origin: OriginFor<T>, basis: WalletCapBasis) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            WalletCapBasisOf::<T>::put(basis);
+
+            Self::deposit_event(Event::WalletCapBasisSet { basis });
+
+            Ok(())
+        }
+
+        /// Withdraw every unbonding chunk whose unbonding period has passed.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::withdraw_unbonded(T::MaxUnlockChunks::get()))]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut chunks = UnlockChunks::<T>::get(&who);
+            let mut amount = BalanceOf::<T>::zero();
+            chunks.retain(|chunk| {
+                if chunk.unlock_at <= now {
+                    amount = amount.saturating_add(chunk.value);
+                    false
+                } else {
+                    true
+                }
+            });
+            ensure!(!amount.is_zero(), Error::<T>::NoUnbondedTokens);
+
+            let new_balance = Balances::<T>::get(&who).checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            Balances::<T>::insert(&who, new_balance);
+            TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(amount));
+
+            if chunks.is_empty() {
+                UnlockChunks::<T>::remove(&who);
+            } else {
+                UnlockChunks::<T>::insert(&who, chunks);
+            }
+
+            Self::deposit_event(Event::UnbondedWithdrawn {
+                account: who,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Stake unbonding tokens again, most recently unstaked first.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::rebond(T::MaxUnlockChunks::get()))]
+        pub fn rebond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Ensure amount is not zero
+            ensure!(!amount.is_zero(), Error::<T>::AmountBelowMinimum);
+
+            // Check if user has sufficient unbonding balance
+            ensure!(Self::unbonding_balance(&who) >= amount, Error::<T>::InsufficientUnbondingBalance);
+
+            let mut chunks = UnlockChunks::<T>::get(&who);
+            let mut remaining = amount;
+            while let Some(last) = chunks.last_mut() {
+                if last.value > remaining {
+                    last.value = last.value.saturating_sub(remaining);
+                    break;
+                }
+                remaining = remaining.saturating_sub(last.value);
+                chunks.pop();
+                if remaining.is_zero() {
+                    break;
+                }
+            }
+
+            // Settle rewards earned on the previous stake
+            Self::settle_rewards(&who);
+
+            let new_staked_balance = StakedBalances::<T>::get(&who).checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            let total_staked = TotalStaked::<T>::get().checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            StakedBalances::<T>::insert(&who, new_staked_balance);
+            TotalStaked::<T>::put(total_staked);
+            TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(amount));
+
+            if chunks.is_empty() {
+                UnlockChunks::<T>::remove(&who);
+            } else {
+                UnlockChunks::<T>::insert(&who, chunks);
+            }
+
+            // Update governance power
+            Self::update_governance_power(&who, new_staked_balance)?;
+
+            // Lock the rebonded tokens
+            Self::update_stake_lock(&who);
+
+            Self::deposit_event(Event::TokensRebonded {
+                account: who,
+                amount,
+            });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -458,53 +1068,185 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Distribute staking rewards to an account.
-        fn distribute_rewards(account: &T::AccountId) -> DispatchResult {
-            let staked_amount = StakedBalances::<T>::get(account);
-            if staked_amount.is_zero() {
+        /// CREATE held freely by accounts subject to the wallet cap: the total supply less
+        /// unallocated, pooled, staked and unbonding CREATE and the balances of exempt accounts.
+        pub fn circulating_supply() -> BalanceOf<T> {
+            let exempt = WalletCapExemptions::<T>::get()
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, account| total.saturating_add(Balances::<T>::get(account)));
+            TotalSupply::<T>::get()
+                .saturating_sub(UnallocatedSupply::<T>::get())
+                .saturating_sub(RewardPool::<T>::get())
+                .saturating_sub(TotalStaked::<T>::get())
+                .saturating_sub(TotalUnbonding::<T>::get())
+                .saturating_sub(exempt)
+        }
+
+        /// Emit `amount` new CREATE to `to`, within the supply cap and the wallet cap.
+        pub(crate) fn do_mint(to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            // Check if minting would exceed max wallet percentage
+            let new_balance = Balances::<T>::get(to).checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            Self::ensure_within_wallet_cap(to, new_balance)?;
+
+            // Check the hard supply cap
+            let total_supply = TotalSupply::<T>::get()
+                .checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            ensure!(total_supply <= T::MaxSupply::get(), Error::<T>::ExceedsMaxSupply);
+
+            Balances::<T>::insert(to, new_balance);
+            TotalSupply::<T>::put(total_supply);
+            Ok(())
+        }
+
+        /// Ensure `account` may hold `new_balance` under the wallet cap.
+        pub(crate) fn ensure_within_wallet_cap(account: &T::AccountId, new_balance: BalanceOf<T>) -> DispatchResult {
+            if WalletCapExemptions::<T>::get().contains(account) {
                 return Ok(());
             }
 
-            // TODO: Fix type conversion issues in reward calculation
-            // let current_block = frame_system::Pallet::<T>::block_number();
-            // let last_reward_block = LastRewardBlock::<T>::get();
-            
-            // if current_block > last_reward_block {
-            //     let blocks_since_last_reward = current_block - last_reward_block;
-            //     let reward_rate = StakingRewardRate::<T>::get();
-                
-            //     // Calculate rewards based on staked amount and time
-            //     let rewards = staked_amount
-            //         .checked_mul(&(blocks_since_last_reward as u128).into())
-            //         .ok_or(Error::<T>::Overflow)?
-            //         .checked_mul(&(reward_rate as u128).into())
-            //         .ok_or(Error::<T>::Overflow)?
-            //         .checked_div(&1_000_000u128.into()) // Reward rate is in parts per million
-            //         .ok_or(Error::<T>::Underflow)?;
-
-            //     let pending_rewards = PendingRewards::<T>::get(account);
-            //     let new_pending_rewards = pending_rewards.checked_add(&rewards)
-            //         .ok_or(Error::<T>::Overflow)?;
-            //     PendingRewards::<T>::insert(account, new_pending_rewards);
-            // }
-
-            // LastRewardBlock::<T>::put(current_block);
+            let supply = match WalletCapBasisOf::<T>::get() {
+                WalletCapBasis::TotalSupply => TotalSupply::<T>::get(),
+                WalletCapBasis::CirculatingSupply => Self::circulating_supply(),
+            };
+            if supply < T::WalletCapBootstrapSupply::get() {
+                return Ok(());
+            }
+
+            let max_allowed = supply
+                .checked_mul(&T::MaxWalletPercentage::get().into())
+                .ok_or(Error::<T>::Overflow)?
+                .checked_div(&100u32.into())
+                .ok_or(Error::<T>::Underflow)?;
+            ensure!(new_balance <= max_allowed, Error::<T>::ExceedsMaxWalletPercentage);
+            Ok(())
+        }
+
+        /// Ensure a vesting schedule locks and unlocks something, and starts before its cliff.
+        pub fn ensure_valid_schedule(schedule: &VestingScheduleOf<T>) -> DispatchResult {
+            ensure!(
+                !schedule.locked.is_zero() && !schedule.per_block.is_zero() &&
+                    schedule.cliff >= schedule.starting_block,
+                Error::<T>::InvalidVestingSchedule
+            );
+            Ok(())
+        }
+
+        /// CREATE a schedule still locks at `now`.
+        pub fn schedule_locked_at(schedule: &VestingScheduleOf<T>, now: BlockNumberFor<T>) -> BalanceOf<T> {
+            if now < schedule.cliff {
+                return schedule.locked;
+            }
+            let blocks: BalanceOf<T> = now.saturating_sub(schedule.starting_block).saturated_into::<u32>().into();
+            schedule.locked.saturating_sub(schedule.per_block.saturating_mul(blocks))
+        }
+
+        /// CREATE that can leave the balance of an account without touching vesting CREATE.
+        ///
+        /// Staked and unbonding CREATE count towards the vesting lock, so vesting CREATE can be
+        /// staked while the rest of the balance stays transferable.
+        pub fn transferable_balance(account: &T::AccountId) -> BalanceOf<T> {
+            let balance = Balances::<T>::get(account);
+            let vesting_locked = VestingLocked::<T>::get(account);
+            if vesting_locked.is_zero() {
+                return balance;
+            }
+            let owned = balance
+                .saturating_add(StakedBalances::<T>::get(account))
+                .saturating_add(Self::unbonding_balance(account));
+            owned.saturating_sub(vesting_locked).min(balance)
+        }
+
+        /// Ensure `amount` can leave the balance of an account without touching vesting CREATE.
+        fn ensure_unlocked(account: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            ensure!(Self::transferable_balance(account) >= amount, Error::<T>::VestingLocked);
             Ok(())
         }
 
-        /// Initialize the CREATE token with default parameters.
-        /// This should be called during genesis.
-        pub fn initialize_token() {
-            // TODO: Fix type conversion issues
-            // Set initial total supply (1,000,000,000 CREATE)
-            // TotalSupply::<T>::put(1_000_000_000_000_000_000_000_000u128);
-            
-            // Set staking reward rate (0.1% per block = 1000 ppm)
-            StakingRewardRate::<T>::put(1000);
-            
-            // TODO: Fix type conversion issues
-            // Set initial last reward block
-            // LastRewardBlock::<T>::put(frame_system::Pallet::<T>::block_number());
+        /// Recompute the vesting lock of an account and drop finished schedules.
+        fn update_vesting(account: &T::AccountId) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut schedules = Vesting::<T>::get(account);
+            let mut unvested = BalanceOf::<T>::zero();
+            schedules.retain(|schedule| {
+                let locked = Self::schedule_locked_at(schedule, now);
+                unvested = unvested.saturating_add(locked);
+                !locked.is_zero()
+            });
+
+            if schedules.is_empty() {
+                Vesting::<T>::remove(account);
+                VestingLocked::<T>::remove(account);
+                Self::deposit_event(Event::VestingCompleted { account: account.clone() });
+            } else {
+                Vesting::<T>::insert(account, schedules);
+                VestingLocked::<T>::insert(account, unvested);
+                Self::deposit_event(Event::VestingUpdated { account: account.clone(), unvested });
+            }
+        }
+
+        /// CREATE of an account still unbonding or waiting to be withdrawn.
+        pub fn unbonding_balance(account: &T::AccountId) -> BalanceOf<T> {
+            UnlockChunks::<T>::get(account)
+                .iter()
+                .fold(Zero::zero(), |total: BalanceOf<T>, chunk| total.saturating_add(chunk.value))
+        }
+
+        /// Lock the staked CREATE of an account, or remove the lock if there is none.
+        fn update_stake_lock(account: &T::AccountId) {
+            let locked = StakedBalances::<T>::get(account);
+            if locked.is_zero() {
+                T::Currency::remove_lock(T::StakingLockId::get(), account);
+            } else {
+                T::Currency::set_lock(T::StakingLockId::get(), account, locked, WithdrawReasons::all());
+            }
+        }
+
+        /// Emit the rewards of the blocks since the last emission into [`RewardPerShare`].
+        ///
+        /// Emission is capped by the reward pool, and nothing is emitted while nothing is staked.
+        fn accrue_rewards() {
+            let now = frame_system::Pallet::<T>::block_number();
+            let last_reward_block = LastRewardBlock::<T>::get();
+            if now <= last_reward_block {
+                return;
+            }
+            LastRewardBlock::<T>::put(now);
+
+            let total_staked = TotalStaked::<T>::get();
+            if total_staked.is_zero() {
+                return;
+            }
+
+            let blocks: BalanceOf<T> = now.saturating_sub(last_reward_block).saturated_into::<u32>().into();
+            let emission = RewardPerBlock::<T>::get()
+                .saturating_mul(blocks)
+                .min(RewardPool::<T>::get());
+            if emission.is_zero() {
+                return;
+            }
+
+            RewardPool::<T>::mutate(|pool| *pool = pool.saturating_sub(emission));
+            RewardPerShare::<T>::mutate(|per_share| {
+                *per_share = per_share.saturating_add(FixedU128::saturating_from_rational(emission, total_staked))
+            });
+        }
+
+        /// Move the rewards an account earned on its current stake into [`PendingRewards`].
+        ///
+        /// Must be called before the account's stake changes.
+        fn settle_rewards(account: &T::AccountId) {
+            Self::accrue_rewards();
+
+            let per_share = RewardPerShare::<T>::get();
+            let earned = per_share
+                .saturating_sub(RewardPerSharePaid::<T>::get(account))
+                .saturating_mul_int(StakedBalances::<T>::get(account));
+            if !earned.is_zero() {
+                PendingRewards::<T>::mutate(account, |pending| *pending = pending.saturating_add(earned));
+            }
+            RewardPerSharePaid::<T>::insert(account, per_share);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,75 @@
+//! Storage migrations for the CREATE token pallet.
+
+/// Replace the unused parts-per-million `StakingRewardRate` with the funded reward pool.
+pub mod v1 {
+    use crate::{Config, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+
+    /// The reward rate as it was stored in version 0.
+    #[frame_support::storage_alias]
+    pub type StakingRewardRate<T: Config> = StorageValue<Pallet<T>, u32, ValueQuery>;
+
+    /// Removes `StakingRewardRate`. Rewards were never paid out under it, so the emission rate
+    /// starts at zero until governance sets one.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            StakingRewardRate::<T>::kill();
+            T::DbWeight::get().writes(1)
+        }
+    }
+
+    /// [`InnerMigrateV0ToV1`] guarded by the pallet's storage version.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Track the total unbonding CREATE, which circulating supply leaves out.
+pub mod v2 {
+    use crate::{BalanceOf, Config, Pallet, TotalUnbonding, UnlockChunks};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_runtime::traits::{Saturating, Zero};
+
+    /// Sets `TotalUnbonding` to the sum of all unlock chunks.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut total = BalanceOf::<T>::zero();
+            for (_, chunks) in UnlockChunks::<T>::iter() {
+                reads += 1;
+                for chunk in chunks {
+                    total = total.saturating_add(chunk.value);
+                }
+            }
+            TotalUnbonding::<T>::put(total);
+            T::DbWeight::get().reads_writes(reads, 1)
+        }
+    }
+
+    /// [`InnerMigrateV1ToV2`] guarded by the pallet's storage version.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -0,0 +1,132 @@
+use crate::{self as pallet_create_token, WalletCapBasis};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, LockIdentifier},
+};
+use frame_system::EnsureRoot;
+use scale_info::TypeInfo;
+use sp_runtime::{BuildStorage, RuntimeDebug};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type CreateToken = pallet_create_token::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+// None of the pallets in the mock hold CREATE, so the tests bring their own hold reasons.
+#[derive(Clone, Copy, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TestHoldReason {
+    Escrow,
+    Deposit,
+}
+
+parameter_types! {
+    pub const StakingLockId: LockIdentifier = *b"crstake ";
+    pub const GovernanceLockId: LockIdentifier = *b"crgovern";
+}
+
+impl pallet_create_token::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type WeightInfo = pallet_create_token::weights::SubstrateWeight<Test>;
+    type MintOrigin = EnsureRoot<u64>;
+    type MaxSupply = ConstU64<MAX_SUPPLY>;
+    type MaxWalletPercentage = ConstU32<5>;
+    type MinGovernanceStake = ConstU64<MIN_GOVERNANCE_STAKE>;
+    type StakingLockId = StakingLockId;
+    type GovernanceLockId = GovernanceLockId;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type UnbondingPeriod = ConstU64<UNBONDING_PERIOD>;
+    type MaxUnlockChunks = ConstU32<MAX_UNLOCK_CHUNKS>;
+    type MaxVestingSchedules = ConstU32<2>;
+    type MaxWalletCapExemptions = ConstU32<2>;
+    type WalletCapBootstrapSupply = ConstU64<BOOTSTRAP_SUPPLY>;
+    type RuntimeHoldReason = TestHoldReason;
+}
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+// Holds no CREATE.
+pub const DAVE: u64 = 4;
+pub const TREASURY: u64 = 10;
+
+pub const MAX_SUPPLY: u64 = 1_000_000;
+pub const INITIAL_SUPPLY: u64 = 500_000;
+// CREATE `ALICE` and `BOB` are allocated at genesis.
+pub const ALLOCATION: u64 = 20_000;
+pub const MIN_GOVERNANCE_STAKE: u64 = 100;
+pub const UNBONDING_PERIOD: u64 = 10;
+pub const MAX_UNLOCK_CHUNKS: u32 = 4;
+pub const BOOTSTRAP_SUPPLY: u64 = 10_000;
+pub const NATIVE_BALANCE: u64 = 1_000_000;
+
+// The default genesis: `INITIAL_SUPPLY` with `ALLOCATION` for `ALICE` and `BOB`, no vesting and
+// no exemptions, with the wallet cap measured against the total supply.
+pub fn genesis() -> pallet_create_token::GenesisConfig<Test> {
+    pallet_create_token::GenesisConfig {
+        initial_supply: INITIAL_SUPPLY,
+        allocations: vec![(ALICE, ALLOCATION), (BOB, ALLOCATION)],
+        vesting: vec![],
+        wallet_cap_exemptions: vec![],
+        wallet_cap_basis: WalletCapBasis::TotalSupply,
+    }
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with(genesis())
+}
+
+// Build genesis storage from `config` and start at block 1.
+pub fn new_test_ext_with(config: pallet_create_token::GenesisConfig<Test>) -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    // The staking and governance locks sit on the native balance, so every account needs one.
+    pallet_balances::GenesisConfig::<Test> {
+        balances: [ALICE, BOB, CHARLIE, DAVE, TREASURY].into_iter().map(|who| (who, NATIVE_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    config.assimilate_storage(&mut storage).unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
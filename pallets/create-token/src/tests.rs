@@ -0,0 +1,756 @@
+use crate::{
+    migrations, mock::*, Error, Event, TotalUnbonding, UnlockChunk, UnlockChunks, UnlockChunksOf, VestingSchedule, WalletCapBasis,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{
+        fungible::{
+            hold::{Inspect as HoldInspect, Mutate as HoldMutate},
+            Inspect, Mutate, Unbalanced,
+        },
+        tokens::{Fortitude, Precision, Preservation, Restriction},
+        OnRuntimeUpgrade, StorageVersion,
+    },
+};
+use sp_runtime::{DispatchError, TokenError};
+
+const REWARD_PER_BLOCK: u64 = 10;
+
+// Fund the reward pool from `ALICE` and emit `REWARD_PER_BLOCK` from the current block on.
+fn start_rewards(pool: u64) {
+    assert_ok!(CreateToken::fund_reward_pool(RuntimeOrigin::signed(ALICE), pool));
+    assert_ok!(CreateToken::set_reward_per_block(RuntimeOrigin::root(), REWARD_PER_BLOCK));
+}
+
+fn stake(who: u64, amount: u64) {
+    assert_ok!(CreateToken::stake_tokens(RuntimeOrigin::signed(who), amount));
+}
+
+// Claim the rewards of `who` and return the amount claimed.
+fn claim(who: u64) -> u64 {
+    let before = CreateToken::balance_of(who);
+    assert_ok!(CreateToken::claim_rewards(RuntimeOrigin::signed(who)));
+    CreateToken::balance_of(who) - before
+}
+
+#[test]
+fn rewards_are_shared_pro_rata_to_stake() {
+    new_test_ext().execute_with(|| {
+        start_rewards(1_000);
+        stake(ALICE, 100);
+        stake(BOB, 300);
+
+        System::set_block_number(11);
+        assert_eq!(claim(ALICE), 25);
+        System::assert_last_event(Event::RewardsClaimed { account: ALICE, amount: 25 }.into());
+        assert_eq!(claim(BOB), 75);
+        assert_eq!(CreateToken::reward_pool(), 900);
+
+        assert_noop!(CreateToken::claim_rewards(RuntimeOrigin::signed(ALICE)), Error::<Test>::NoRewardsAvailable);
+    });
+}
+
+#[test]
+fn rewards_are_settled_when_stake_changes() {
+    new_test_ext().execute_with(|| {
+        start_rewards(1_000);
+        stake(ALICE, 100);
+
+        // Alice earns everything until Bob joins
+        System::set_block_number(11);
+        stake(BOB, 100);
+        assert_eq!(CreateToken::pending_rewards(ALICE), 0);
+
+        // Unstaking settles what Alice earned on her old stake
+        System::set_block_number(21);
+        assert_ok!(CreateToken::unstake_tokens(RuntimeOrigin::signed(ALICE), 100));
+        assert_eq!(CreateToken::pending_rewards(ALICE), 150);
+
+        // Unbonding CREATE earns nothing, rebonding resumes earning from then on
+        System::set_block_number(31);
+        assert_ok!(CreateToken::rebond(RuntimeOrigin::signed(ALICE), 100));
+        assert_eq!(CreateToken::pending_rewards(ALICE), 150);
+
+        System::set_block_number(41);
+        assert_eq!(claim(ALICE), 200);
+        assert_eq!(claim(BOB), 50 + 100 + 50);
+    });
+}
+
+#[test]
+fn emission_is_capped_by_the_reward_pool() {
+    new_test_ext().execute_with(|| {
+        start_rewards(50);
+        stake(ALICE, 100);
+
+        System::set_block_number(21);
+        assert_eq!(claim(ALICE), 50);
+        assert_eq!(CreateToken::reward_pool(), 0);
+
+        // Topping up the pool resumes emission without paying for the dry blocks
+        System::set_block_number(31);
+        assert_ok!(CreateToken::fund_reward_pool(RuntimeOrigin::signed(BOB), 1_000));
+        System::set_block_number(32);
+        assert_eq!(claim(ALICE), REWARD_PER_BLOCK);
+    });
+}
+
+#[test]
+fn nothing_is_emitted_while_nothing_is_staked() {
+    new_test_ext().execute_with(|| {
+        start_rewards(1_000);
+
+        System::set_block_number(11);
+        stake(ALICE, 100);
+        assert_eq!(CreateToken::reward_pool(), 1_000);
+
+        System::set_block_number(12);
+        assert_eq!(claim(ALICE), REWARD_PER_BLOCK);
+    });
+}
+
+#[test]
+fn reward_rate_changes_apply_from_the_current_block() {
+    new_test_ext().execute_with(|| {
+        start_rewards(1_000);
+        stake(ALICE, 100);
+
+        System::set_block_number(11);
+        assert_noop!(CreateToken::set_reward_per_block(RuntimeOrigin::signed(ALICE), 1), DispatchError::BadOrigin);
+        assert_ok!(CreateToken::set_reward_per_block(RuntimeOrigin::root(), 1));
+        System::assert_last_event(Event::RewardRateUpdated { reward_per_block: 1 }.into());
+
+        System::set_block_number(21);
+        assert_eq!(claim(ALICE), 100 + 10);
+    });
+}
+
+#[test]
+fn funding_the_pool_moves_create_out_of_the_funder_balance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreateToken::fund_reward_pool(RuntimeOrigin::signed(ALICE), 1_000));
+        System::assert_last_event(Event::RewardPoolFunded { from: ALICE, amount: 1_000 }.into());
+        assert_eq!(CreateToken::balance_of(ALICE), ALLOCATION - 1_000);
+        assert_eq!(CreateToken::total_supply(), INITIAL_SUPPLY);
+
+        assert_noop!(
+            CreateToken::fund_reward_pool(RuntimeOrigin::signed(DAVE), 1),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn reward_pool_migration_removes_the_old_rate() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<CreateToken>();
+        migrations::v1::StakingRewardRate::<Test>::put(500);
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert!(!migrations::v1::StakingRewardRate::<Test>::exists());
+        assert_eq!(CreateToken::reward_per_block(), 0);
+        assert_eq!(StorageVersion::get::<CreateToken>(), 1);
+    });
+}
+
+#[test]
+fn only_the_mint_origin_can_mint() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(CreateToken::mint_tokens(RuntimeOrigin::signed(ALICE), ALICE, 1), DispatchError::BadOrigin);
+        assert_noop!(CreateToken::allocate_tokens(RuntimeOrigin::signed(ALICE), ALICE, 1), DispatchError::BadOrigin);
+
+        assert_ok!(CreateToken::mint_tokens(RuntimeOrigin::root(), CHARLIE, 1_000));
+        System::assert_last_event(Event::TokensMinted { account: CHARLIE, amount: 1_000 }.into());
+        assert_eq!(CreateToken::balance_of(CHARLIE), 1_000);
+        assert_eq!(CreateToken::total_supply(), INITIAL_SUPPLY + 1_000);
+    });
+}
+
+#[test]
+fn minting_over_the_max_supply_is_rejected() {
+    let mut config = genesis();
+    config.wallet_cap_exemptions = vec![TREASURY];
+    new_test_ext_with(config).execute_with(|| {
+        let headroom = MAX_SUPPLY - INITIAL_SUPPLY;
+        assert_noop!(
+            CreateToken::mint_tokens(RuntimeOrigin::root(), TREASURY, headroom + 1),
+            Error::<Test>::ExceedsMaxSupply
+        );
+
+        assert_ok!(CreateToken::mint_tokens(RuntimeOrigin::root(), TREASURY, headroom));
+        assert_eq!(CreateToken::total_supply(), MAX_SUPPLY);
+
+        // Burning makes room to mint again
+        assert_noop!(CreateToken::mint_tokens(RuntimeOrigin::root(), TREASURY, 1), Error::<Test>::ExceedsMaxSupply);
+        assert_ok!(CreateToken::burn_tokens(RuntimeOrigin::signed(TREASURY), 1));
+        assert_ok!(CreateToken::mint_tokens(RuntimeOrigin::root(), TREASURY, 1));
+    });
+}
+
+#[test]
+fn allocations_come_out_of_the_unallocated_supply() {
+    new_test_ext().execute_with(|| {
+        let unallocated = INITIAL_SUPPLY - 2 * ALLOCATION;
+        assert_eq!(CreateToken::unallocated_supply(), unallocated);
+
+        assert_ok!(CreateToken::allocate_tokens(RuntimeOrigin::root(), CHARLIE, 1_000));
+        System::assert_last_event(Event::TokensAllocated { account: CHARLIE, amount: 1_000 }.into());
+        assert_eq!(CreateToken::balance_of(CHARLIE), 1_000);
+        assert_eq!(CreateToken::unallocated_supply(), unallocated - 1_000);
+        assert_eq!(CreateToken::total_supply(), INITIAL_SUPPLY);
+
+        assert_noop!(
+            CreateToken::allocate_tokens(RuntimeOrigin::root(), CHARLIE, unallocated),
+            Error::<Test>::InsufficientUnallocatedSupply
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "initial supply exceeds the maximum supply")]
+fn genesis_supply_over_the_max_supply_is_rejected() {
+    let mut config = genesis();
+    config.initial_supply = MAX_SUPPLY + 1;
+    new_test_ext_with(config);
+}
+
+const VESTING: u64 = 10_000;
+const VESTING_PER_BLOCK: u64 = 100;
+const VESTING_CLIFF: u64 = 10;
+
+// Allocate `VESTING` CREATE to `CHARLIE`, all of it vesting from block 0 behind `VESTING_CLIFF`.
+fn vesting_genesis() -> crate::GenesisConfig<Test> {
+    let mut config = genesis();
+    config.allocations.push((CHARLIE, VESTING));
+    config.vesting = vec![(CHARLIE, 0, VESTING_CLIFF, VESTING_PER_BLOCK, VESTING)];
+    config
+}
+
+fn schedule(locked: u64, per_block: u64, starting_block: u64, cliff: u64) -> VestingSchedule<u64, u64> {
+    VestingSchedule { locked, per_block, starting_block, cliff }
+}
+
+#[test]
+fn vesting_create_unlocks_linearly_after_the_cliff() {
+    new_test_ext_with(vesting_genesis()).execute_with(|| {
+        assert_eq!(CreateToken::vesting_locked(CHARLIE), VESTING);
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(CHARLIE), ALICE, 1),
+            Error::<Test>::VestingLocked
+        );
+
+        // Nothing unlocks before the cliff
+        System::set_block_number(VESTING_CLIFF - 1);
+        assert_ok!(CreateToken::vest(RuntimeOrigin::signed(CHARLIE)));
+        assert_eq!(CreateToken::vesting_locked(CHARLIE), VESTING);
+
+        // What vested by the cliff unlocks at once
+        System::set_block_number(20);
+        assert_ok!(CreateToken::vest(RuntimeOrigin::signed(CHARLIE)));
+        System::assert_last_event(Event::VestingUpdated { account: CHARLIE, unvested: 8_000 }.into());
+        assert_eq!(CreateToken::transferable_balance(&CHARLIE), 2_000);
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(CHARLIE), ALICE, 2_001),
+            Error::<Test>::VestingLocked
+        );
+        assert_ok!(CreateToken::transfer_tokens(RuntimeOrigin::signed(CHARLIE), ALICE, 2_000));
+
+        System::set_block_number(VESTING / VESTING_PER_BLOCK);
+        assert_ok!(CreateToken::vest(RuntimeOrigin::signed(CHARLIE)));
+        System::assert_last_event(Event::VestingCompleted { account: CHARLIE }.into());
+        assert!(CreateToken::vesting(CHARLIE).is_empty());
+        assert_eq!(CreateToken::vesting_locked(CHARLIE), 0);
+        assert_eq!(CreateToken::transferable_balance(&CHARLIE), VESTING - 2_000);
+
+        assert_noop!(CreateToken::vest(RuntimeOrigin::signed(CHARLIE)), Error::<Test>::NotVesting);
+    });
+}
+
+#[test]
+fn vesting_create_can_be_staked() {
+    new_test_ext_with(vesting_genesis()).execute_with(|| {
+        stake(CHARLIE, 6_000);
+        assert_eq!(CreateToken::transferable_balance(&CHARLIE), 0);
+
+        // Staked CREATE counts towards the vesting lock, so what unlocks stays transferable
+        System::set_block_number(20);
+        assert_ok!(CreateToken::vest(RuntimeOrigin::signed(CHARLIE)));
+        assert_eq!(CreateToken::transferable_balance(&CHARLIE), 2_000);
+        assert_ok!(CreateToken::burn_tokens(RuntimeOrigin::signed(CHARLIE), 2_000));
+        assert_noop!(
+            CreateToken::burn_tokens(RuntimeOrigin::signed(CHARLIE), 1),
+            Error::<Test>::VestingLocked
+        );
+    });
+}
+
+#[test]
+fn vested_transfer_locks_the_create_of_the_recipient() {
+    new_test_ext().execute_with(|| {
+        let vesting = schedule(1_000, 10, 1, 1);
+        assert_ok!(CreateToken::vested_transfer(RuntimeOrigin::signed(ALICE), DAVE, vesting.clone()));
+        System::assert_last_event(Event::VestingScheduleAdded { account: DAVE, schedule: vesting }.into());
+        assert_eq!(CreateToken::balance_of(ALICE), ALLOCATION - 1_000);
+        assert_eq!(CreateToken::balance_of(DAVE), 1_000);
+        assert_eq!(CreateToken::vesting_locked(DAVE), 1_000);
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(DAVE), BOB, 1),
+            Error::<Test>::VestingLocked
+        );
+
+        System::set_block_number(11);
+        assert_ok!(CreateToken::vest(RuntimeOrigin::signed(DAVE)));
+        assert_ok!(CreateToken::transfer_tokens(RuntimeOrigin::signed(DAVE), BOB, 100));
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(DAVE), BOB, 1),
+            Error::<Test>::VestingLocked
+        );
+    });
+}
+
+#[test]
+fn vested_transfer_to_self_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CreateToken::vested_transfer(RuntimeOrigin::signed(ALICE), ALICE, schedule(1_000, 1_000, 1, 1)),
+            Error::<Test>::TransferToSelf
+        );
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(ALICE), ALICE, 1_000),
+            Error::<Test>::TransferToSelf
+        );
+        assert_eq!(CreateToken::balance_of(ALICE), ALLOCATION);
+    });
+}
+
+#[test]
+fn vested_transfer_rejects_invalid_schedules() {
+    new_test_ext().execute_with(|| {
+        for invalid in [schedule(0, 10, 1, 1), schedule(1_000, 0, 1, 1), schedule(1_000, 10, 5, 4)] {
+            assert_noop!(
+                CreateToken::vested_transfer(RuntimeOrigin::signed(ALICE), DAVE, invalid),
+                Error::<Test>::InvalidVestingSchedule
+            );
+        }
+    });
+}
+
+#[test]
+fn vested_transfer_is_bounded_per_recipient() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            assert_ok!(CreateToken::vested_transfer(RuntimeOrigin::signed(ALICE), DAVE, schedule(100, 1, 1, 1)));
+        }
+        assert_eq!(CreateToken::vesting(DAVE).len(), 2);
+        assert_noop!(
+            CreateToken::vested_transfer(RuntimeOrigin::signed(ALICE), DAVE, schedule(100, 1, 1, 1)),
+            Error::<Test>::TooManyVestingSchedules
+        );
+    });
+}
+
+#[test]
+fn vested_transfer_cannot_spend_vesting_create() {
+    new_test_ext_with(vesting_genesis()).execute_with(|| {
+        assert_noop!(
+            CreateToken::vested_transfer(RuntimeOrigin::signed(CHARLIE), DAVE, schedule(100, 1, 1, 1)),
+            Error::<Test>::VestingLocked
+        );
+    });
+}
+
+#[test]
+fn wallet_cap_limits_balances_to_a_share_of_the_total_supply() {
+    new_test_ext().execute_with(|| {
+        // 5% of `INITIAL_SUPPLY` is 25_000 and `ALICE` holds 20_000
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(BOB), ALICE, 5_001),
+            Error::<Test>::ExceedsMaxWalletPercentage
+        );
+        assert_noop!(
+            CreateToken::mint_tokens(RuntimeOrigin::root(), ALICE, 5_001),
+            Error::<Test>::ExceedsMaxWalletPercentage
+        );
+        assert_ok!(CreateToken::transfer_tokens(RuntimeOrigin::signed(BOB), ALICE, 5_000));
+        assert_eq!(CreateToken::balance_of(ALICE), 25_000);
+    });
+}
+
+#[test]
+fn exempt_accounts_are_not_capped() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CreateToken::add_wallet_cap_exemption(RuntimeOrigin::signed(ALICE), TREASURY),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(CreateToken::add_wallet_cap_exemption(RuntimeOrigin::root(), TREASURY));
+        System::assert_last_event(Event::WalletCapExemptionAdded { account: TREASURY }.into());
+        assert_noop!(
+            CreateToken::add_wallet_cap_exemption(RuntimeOrigin::root(), TREASURY),
+            Error::<Test>::AlreadyWalletCapExempt
+        );
+
+        assert_ok!(CreateToken::mint_tokens(RuntimeOrigin::root(), TREASURY, 30_000));
+        assert_ok!(CreateToken::transfer_tokens(RuntimeOrigin::signed(BOB), TREASURY, 1_000));
+
+        // Exemptions are bounded
+        assert_ok!(CreateToken::add_wallet_cap_exemption(RuntimeOrigin::root(), CHARLIE));
+        assert_noop!(
+            CreateToken::add_wallet_cap_exemption(RuntimeOrigin::root(), DAVE),
+            Error::<Test>::TooManyWalletCapExemptions
+        );
+
+        // A formerly exempt account keeps its balance but cannot receive more while above the cap
+        assert_ok!(CreateToken::remove_wallet_cap_exemption(RuntimeOrigin::root(), TREASURY));
+        System::assert_last_event(Event::WalletCapExemptionRemoved { account: TREASURY }.into());
+        assert_eq!(CreateToken::wallet_cap_exemptions().to_vec(), vec![CHARLIE]);
+        assert_eq!(CreateToken::balance_of(TREASURY), 31_000);
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(BOB), TREASURY, 1),
+            Error::<Test>::ExceedsMaxWalletPercentage
+        );
+        assert_noop!(
+            CreateToken::remove_wallet_cap_exemption(RuntimeOrigin::root(), TREASURY),
+            Error::<Test>::NotWalletCapExempt
+        );
+    });
+}
+
+#[test]
+fn wallet_cap_waits_for_the_bootstrap_supply() {
+    let mut config = genesis();
+    config.allocations = vec![(ALICE, 5_000)];
+    config.wallet_cap_basis = WalletCapBasis::CirculatingSupply;
+    new_test_ext_with(config).execute_with(|| {
+        // Below `BOOTSTRAP_SUPPLY` in circulation a single account may hold all of it
+        assert_eq!(CreateToken::circulating_supply(), 5_000);
+        assert_ok!(CreateToken::transfer_tokens(RuntimeOrigin::signed(ALICE), BOB, 4_000));
+
+        // From then on 5% of the circulating supply, 550, is the cap
+        assert_ok!(CreateToken::allocate_tokens(RuntimeOrigin::root(), BOB, 6_000));
+        assert_eq!(CreateToken::circulating_supply(), 11_000);
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(ALICE), CHARLIE, 551),
+            Error::<Test>::ExceedsMaxWalletPercentage
+        );
+        assert_ok!(CreateToken::transfer_tokens(RuntimeOrigin::signed(ALICE), CHARLIE, 550));
+    });
+}
+
+#[test]
+fn circulating_supply_excludes_create_held_out_of_circulation() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(CreateToken::circulating_supply(), 2 * ALLOCATION);
+
+        assert_ok!(CreateToken::fund_reward_pool(RuntimeOrigin::signed(ALICE), 1_000));
+        assert_eq!(CreateToken::circulating_supply(), 2 * ALLOCATION - 1_000);
+
+        stake(BOB, 2_000);
+        assert_eq!(CreateToken::circulating_supply(), 2 * ALLOCATION - 3_000);
+
+        // Unbonding CREATE stays out of circulation until it is withdrawn
+        assert_ok!(CreateToken::unstake_tokens(RuntimeOrigin::signed(BOB), 500));
+        assert_eq!(CreateToken::circulating_supply(), 2 * ALLOCATION - 3_000);
+        System::set_block_number(1 + UNBONDING_PERIOD);
+        assert_ok!(CreateToken::withdraw_unbonded(RuntimeOrigin::signed(BOB)));
+        assert_eq!(CreateToken::circulating_supply(), 2 * ALLOCATION - 2_500);
+
+        assert_ok!(CreateToken::add_wallet_cap_exemption(RuntimeOrigin::root(), ALICE));
+        assert_eq!(CreateToken::circulating_supply(), ALLOCATION - 1_500);
+
+        assert_ok!(CreateToken::allocate_tokens(RuntimeOrigin::root(), CHARLIE, 100));
+        assert_eq!(CreateToken::circulating_supply(), ALLOCATION - 1_400);
+    });
+}
+
+#[test]
+fn wallet_cap_basis_is_set_by_the_update_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CreateToken::set_wallet_cap_basis(RuntimeOrigin::signed(ALICE), WalletCapBasis::CirculatingSupply),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(CreateToken::set_wallet_cap_basis(RuntimeOrigin::root(), WalletCapBasis::CirculatingSupply));
+        System::assert_last_event(Event::WalletCapBasisSet { basis: WalletCapBasis::CirculatingSupply }.into());
+        assert_eq!(CreateToken::wallet_cap_basis(), WalletCapBasis::CirculatingSupply);
+
+        // 5% of the 40_000 in circulation is 2_000, well below what `ALICE` already holds
+        assert_noop!(
+            CreateToken::transfer_tokens(RuntimeOrigin::signed(BOB), ALICE, 1),
+            Error::<Test>::ExceedsMaxWalletPercentage
+        );
+    });
+}
+
+// Unstake `amount` of the stake of `who`.
+fn unstake(who: u64, amount: u64) {
+    assert_ok!(CreateToken::unstake_tokens(RuntimeOrigin::signed(who), amount));
+}
+
+// The unbonding chunks of `who` as `(value, unlock_at)` pairs, oldest first.
+fn chunks(who: u64) -> Vec<(u64, u64)> {
+    UnlockChunks::<Test>::get(who).iter().map(|chunk| (chunk.value, chunk.unlock_at)).collect()
+}
+
+// The native amount locked by staking for `who`.
+fn staking_lock(who: u64) -> u64 {
+    Balances::locks(&who).iter().filter(|lock| lock.id == StakingLockId::get()).map(|lock| lock.amount).sum()
+}
+
+#[test]
+fn unbonded_create_is_withdrawable_after_the_unbonding_period() {
+    new_test_ext().execute_with(|| {
+        stake(ALICE, 100);
+        unstake(ALICE, 100);
+
+        System::set_block_number(UNBONDING_PERIOD);
+        assert_noop!(
+            CreateToken::withdraw_unbonded(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::NoUnbondedTokens
+        );
+
+        System::set_block_number(1 + UNBONDING_PERIOD);
+        assert_ok!(CreateToken::withdraw_unbonded(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(CreateToken::balance_of(ALICE), ALLOCATION);
+        assert!(chunks(ALICE).is_empty());
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+    });
+}
+
+#[test]
+fn unstakes_in_the_same_block_share_a_chunk() {
+    new_test_ext().execute_with(|| {
+        stake(ALICE, 100);
+        unstake(ALICE, 10);
+        unstake(ALICE, 20);
+        assert_eq!(chunks(ALICE), vec![(30, 1 + UNBONDING_PERIOD)]);
+
+        System::set_block_number(2);
+        unstake(ALICE, 30);
+        assert_eq!(chunks(ALICE), vec![(30, 1 + UNBONDING_PERIOD), (30, 2 + UNBONDING_PERIOD)]);
+    });
+}
+
+#[test]
+fn unbonding_chunks_are_bounded() {
+    new_test_ext().execute_with(|| {
+        stake(ALICE, 100);
+        for block in 1..=MAX_UNLOCK_CHUNKS as u64 {
+            System::set_block_number(block);
+            unstake(ALICE, 10);
+        }
+
+        System::set_block_number(MAX_UNLOCK_CHUNKS as u64 + 1);
+        assert_noop!(
+            CreateToken::unstake_tokens(RuntimeOrigin::signed(ALICE), 10),
+            Error::<Test>::TooManyUnlockChunks
+        );
+    });
+}
+
+#[test]
+fn rebond_takes_the_newest_chunks_first() {
+    new_test_ext().execute_with(|| {
+        stake(ALICE, 100);
+        for (block, amount) in [(1, 10), (2, 20), (3, 30)] {
+            System::set_block_number(block);
+            unstake(ALICE, amount);
+        }
+
+        // The newest chunk is consumed whole and the one before it split
+        assert_ok!(CreateToken::rebond(RuntimeOrigin::signed(ALICE), 40));
+        assert_eq!(chunks(ALICE), vec![(10, 1 + UNBONDING_PERIOD), (10, 2 + UNBONDING_PERIOD)]);
+        assert_eq!(CreateToken::staked_balance(ALICE), 80);
+        assert_eq!(TotalUnbonding::<Test>::get(), 20);
+
+        assert_noop!(
+            CreateToken::rebond(RuntimeOrigin::signed(ALICE), 21),
+            Error::<Test>::InsufficientUnbondingBalance
+        );
+    });
+}
+
+#[test]
+fn staking_lock_covers_only_staked_create() {
+    new_test_ext().execute_with(|| {
+        stake(ALICE, 100);
+        assert_eq!(staking_lock(ALICE), 100);
+
+        unstake(ALICE, 40);
+        assert_eq!(staking_lock(ALICE), 60);
+
+        assert_ok!(CreateToken::rebond(RuntimeOrigin::signed(ALICE), 10));
+        assert_eq!(staking_lock(ALICE), 70);
+
+        unstake(ALICE, 70);
+        assert_eq!(staking_lock(ALICE), 0);
+    });
+}
+
+#[test]
+fn unbonding_migration_sums_the_unlock_chunks() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<CreateToken>();
+        let chunk = |value| UnlockChunk { value, unlock_at: UNBONDING_PERIOD };
+        UnlockChunks::<Test>::insert(ALICE, UnlockChunksOf::<Test>::truncate_from(vec![chunk(100), chunk(200)]));
+        UnlockChunks::<Test>::insert(BOB, UnlockChunksOf::<Test>::truncate_from(vec![chunk(300)]));
+
+        migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(TotalUnbonding::<Test>::get(), 600);
+        assert_eq!(StorageVersion::get::<CreateToken>(), 2);
+    });
+}
+
+#[test]
+fn fungible_reducible_balance_excludes_vesting_and_staked_create_even_when_forced() {
+    new_test_ext_with(vesting_genesis()).execute_with(|| {
+        stake(CHARLIE, 1_000);
+        assert_eq!(<CreateToken as Inspect<_>>::total_balance(&CHARLIE), VESTING);
+        for force in [Fortitude::Polite, Fortitude::Force] {
+            assert_eq!(CreateToken::reducible_balance(&CHARLIE, Preservation::Expendable, force), 0);
+        }
+        assert_noop!(
+            <CreateToken as Mutate<_>>::burn_from(
+                &CHARLIE,
+                1,
+                Preservation::Expendable,
+                Precision::Exact,
+                Fortitude::Force
+            ),
+            TokenError::FundsUnavailable
+        );
+
+        System::set_block_number(20);
+        assert_ok!(CreateToken::vest(RuntimeOrigin::signed(CHARLIE)));
+        assert_eq!(CreateToken::reducible_balance(&CHARLIE, Preservation::Expendable, Fortitude::Force), 2_000);
+    });
+}
+
+#[test]
+fn fungible_mint_into_stays_within_the_max_supply_and_wallet_cap() {
+    let mut config = genesis();
+    config.wallet_cap_exemptions = vec![TREASURY];
+    new_test_ext_with(config).execute_with(|| {
+        assert_noop!(
+            <CreateToken as Mutate<_>>::mint_into(&ALICE, 5_001),
+            Error::<Test>::ExceedsMaxWalletPercentage
+        );
+        assert_noop!(
+            <CreateToken as Mutate<_>>::mint_into(&TREASURY, MAX_SUPPLY - INITIAL_SUPPLY + 1),
+            Error::<Test>::ExceedsMaxSupply
+        );
+
+        assert_ok!(<CreateToken as Mutate<_>>::mint_into(&TREASURY, MAX_SUPPLY - INITIAL_SUPPLY));
+        System::assert_last_event(Event::TokensMinted { account: TREASURY, amount: MAX_SUPPLY - INITIAL_SUPPLY }.into());
+        assert_eq!(CreateToken::total_issuance(), MAX_SUPPLY);
+    });
+}
+
+#[test]
+fn fungible_unbalanced_writes_respect_the_wallet_cap_and_max_supply() {
+    new_test_ext_with(vesting_genesis()).execute_with(|| {
+        assert_noop!(
+            <CreateToken as Unbalanced<_>>::increase_balance(&ALICE, 5_001, Precision::Exact),
+            TokenError::Blocked
+        );
+        assert_eq!(<CreateToken as Unbalanced<_>>::increase_balance(&ALICE, 5_000, Precision::Exact), Ok(5_000));
+
+        <CreateToken as Unbalanced<_>>::set_total_issuance(MAX_SUPPLY + 1);
+        assert_eq!(CreateToken::total_issuance(), MAX_SUPPLY);
+    });
+}
+
+#[test]
+fn fungible_transfer_respects_the_wallet_cap_and_vesting() {
+    new_test_ext_with(vesting_genesis()).execute_with(|| {
+        assert_noop!(
+            <CreateToken as Mutate<_>>::transfer(&BOB, &ALICE, 5_001, Preservation::Expendable),
+            TokenError::Blocked
+        );
+        assert_noop!(
+            <CreateToken as Mutate<_>>::transfer(&CHARLIE, &DAVE, 1, Preservation::Expendable),
+            TokenError::Frozen
+        );
+
+        assert_ok!(<CreateToken as Mutate<_>>::transfer(&BOB, &DAVE, 1_000, Preservation::Expendable));
+        System::assert_last_event(Event::TokensTransferred { from: BOB, to: DAVE, amount: 1_000 }.into());
+        assert_eq!(CreateToken::balance_of(DAVE), 1_000);
+    });
+}
+
+#[test]
+fn held_create_stays_in_the_total_balance_until_released() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreateToken::hold(&TestHoldReason::Escrow, &ALICE, 1_000));
+        assert_ok!(CreateToken::hold(&TestHoldReason::Deposit, &ALICE, 500));
+        System::assert_last_event(
+            Event::TokensHeld { reason: TestHoldReason::Deposit, account: ALICE, amount: 500 }.into(),
+        );
+        assert_eq!(CreateToken::balance_of(ALICE), ALLOCATION - 1_500);
+        assert_eq!(CreateToken::balance_on_hold(&TestHoldReason::Escrow, &ALICE), 1_000);
+        assert_eq!(CreateToken::total_balance_on_hold(&ALICE), 1_500);
+        assert_eq!(<CreateToken as Inspect<_>>::total_balance(&ALICE), ALLOCATION);
+
+        assert_ok!(CreateToken::release(&TestHoldReason::Escrow, &ALICE, 1_000, Precision::Exact));
+        System::assert_last_event(
+            Event::TokensReleased { reason: TestHoldReason::Escrow, account: ALICE, amount: 1_000 }.into(),
+        );
+        assert_eq!(CreateToken::balance_of(ALICE), ALLOCATION - 500);
+        assert_eq!(CreateToken::total_balance_on_hold(&ALICE), 500);
+        assert_noop!(
+            CreateToken::release(&TestHoldReason::Escrow, &ALICE, 1, Precision::Exact),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn vesting_create_cannot_be_held() {
+    new_test_ext_with(vesting_genesis()).execute_with(|| {
+        assert_noop!(
+            CreateToken::hold(&TestHoldReason::Escrow, &CHARLIE, 1),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn held_create_can_be_transferred_or_burned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreateToken::hold(&TestHoldReason::Escrow, &ALICE, 1_000));
+
+        assert_ok!(CreateToken::transfer_on_hold(
+            &TestHoldReason::Escrow,
+            &ALICE,
+            &DAVE,
+            400,
+            Precision::Exact,
+            Restriction::Free,
+            Fortitude::Polite,
+        ));
+        System::assert_last_event(
+            Event::HeldTokensTransferred { reason: TestHoldReason::Escrow, from: ALICE, to: DAVE, amount: 400 }.into(),
+        );
+        assert_eq!(CreateToken::balance_of(DAVE), 400);
+
+        assert_ok!(CreateToken::transfer_on_hold(
+            &TestHoldReason::Escrow,
+            &ALICE,
+            &DAVE,
+            100,
+            Precision::Exact,
+            Restriction::OnHold,
+            Fortitude::Polite,
+        ));
+        assert_eq!(CreateToken::balance_on_hold(&TestHoldReason::Escrow, &DAVE), 100);
+
+        assert_ok!(CreateToken::burn_held(&TestHoldReason::Escrow, &ALICE, 500, Precision::Exact, Fortitude::Polite));
+        System::assert_last_event(
+            Event::HeldTokensBurned { reason: TestHoldReason::Escrow, account: ALICE, amount: 500 }.into(),
+        );
+        assert_eq!(CreateToken::total_balance_on_hold(&ALICE), 0);
+        assert_eq!(CreateToken::total_supply(), INITIAL_SUPPLY - 500);
+    });
+}
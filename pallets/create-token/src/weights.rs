@@ -43,6 +43,16 @@ pub trait WeightInfo {
 	fn stake_tokens() -> Weight;
 	fn unstake_tokens() -> Weight;
 	fn claim_rewards() -> Weight;
+	fn fund_reward_pool() -> Weight;
+	fn set_reward_per_block() -> Weight;
+	fn withdraw_unbonded(n: u32, ) -> Weight;
+	fn rebond(n: u32, ) -> Weight;
+	fn allocate_tokens() -> Weight;
+	fn vest(n: u32, ) -> Weight;
+	fn vested_transfer(n: u32, ) -> Weight;
+	fn add_wallet_cap_exemption() -> Weight;
+	fn remove_wallet_cap_exemption() -> Weight;
+	fn set_wallet_cap_basis() -> Weight;
 }
 
 /// Weights for `pallet_create_token` using the Substrate node and recommended hardware.
@@ -52,20 +62,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `CreateToken::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CreateToken::TotalSupply` (r:1 w:1)
 	/// Proof: `CreateToken::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::WalletCapExemptions` (r:1 w:0)
+	/// Proof: `CreateToken::WalletCapExemptions` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::WalletCapBasisOf` (r:1 w:0)
+	/// Proof: `CreateToken::WalletCapBasisOf` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::UnallocatedSupply` (r:1 w:0)
+	/// Proof: `CreateToken::UnallocatedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:0)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalStaked` (r:1 w:0)
+	/// Proof: `CreateToken::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalUnbonding` (r:1 w:0)
+	/// Proof: `CreateToken::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn mint_tokens() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `3514`
+		//  Estimated: `83788`
 		//  Minimum execution time: 20_000_000 picoseconds.
 		Weight::from_parts(20_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3514))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(Weight::from_parts(0, 83788))
+			.saturating_add(T::DbWeight::get().reads(40))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `CreateToken::Balances` (r:1 w:1)
 	/// Proof: `CreateToken::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CreateToken::TotalSupply` (r:1 w:1)
 	/// Proof: `CreateToken::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::VestingLocked` (r:1 w:0)
+	/// Proof: `CreateToken::VestingLocked` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::StakedBalances` (r:1 w:0)
+	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::UnlockChunks` (r:1 w:0)
+	/// Proof: `CreateToken::UnlockChunks` (`max_values`: None, `max_size`: Some(675), added: 3150, mode: `MaxEncodedLen`)
 	fn burn_tokens() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -73,19 +101,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Minimum execution time: 20_000_000 picoseconds.
 		Weight::from_parts(20_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3514))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `CreateToken::Balances` (r:2 w:2)
 	/// Proof: `CreateToken::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::VestingLocked` (r:1 w:0)
+	/// Proof: `CreateToken::VestingLocked` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::StakedBalances` (r:1 w:0)
+	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::UnlockChunks` (r:1 w:0)
+	/// Proof: `CreateToken::UnlockChunks` (`max_values`: None, `max_size`: Some(675), added: 3150, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::WalletCapExemptions` (r:1 w:0)
+	/// Proof: `CreateToken::WalletCapExemptions` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::WalletCapBasisOf` (r:1 w:0)
+	/// Proof: `CreateToken::WalletCapBasisOf` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::UnallocatedSupply` (r:1 w:0)
+	/// Proof: `CreateToken::UnallocatedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:0)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalStaked` (r:1 w:0)
+	/// Proof: `CreateToken::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalUnbonding` (r:1 w:0)
+	/// Proof: `CreateToken::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn transfer_tokens() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `6048`
+		//  Estimated: `83788`
 		//  Minimum execution time: 30_000_000 picoseconds.
 		Weight::from_parts(30_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 6048))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(Weight::from_parts(0, 83788))
+			.saturating_add(T::DbWeight::get().reads(44))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `CreateToken::Balances` (r:1 w:1)
@@ -98,15 +144,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `CreateToken::GovernancePower` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CreateToken::TotalGovernancePower` (r:1 w:1)
 	/// Proof: `CreateToken::TotalGovernancePower` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::LastRewardBlock` (r:1 w:1)
+	/// Proof: `CreateToken::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerBlock` (r:1 w:0)
+	/// Proof: `CreateToken::RewardPerBlock` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerShare` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerShare` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerSharePaid` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerSharePaid` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::PendingRewards` (r:1 w:1)
+	/// Proof: `CreateToken::PendingRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn stake_tokens() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `8602`
-		//  Minimum execution time: 40_000_000 picoseconds.
-		Weight::from_parts(40_000_000, 0)
+		//  Minimum execution time: 55_000_000 picoseconds.
+		Weight::from_parts(55_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 8602))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: `CreateToken::StakedBalances` (r:1 w:1)
 	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -118,20 +176,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `CreateToken::GovernancePower` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CreateToken::TotalGovernancePower` (r:1 w:1)
 	/// Proof: `CreateToken::TotalGovernancePower` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::LastRewardBlock` (r:1 w:1)
+	/// Proof: `CreateToken::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerBlock` (r:1 w:0)
+	/// Proof: `CreateToken::RewardPerBlock` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerShare` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerShare` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerSharePaid` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerSharePaid` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::PendingRewards` (r:1 w:1)
+	/// Proof: `CreateToken::PendingRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::UnlockChunks` (r:1 w:1)
+	/// Proof: `CreateToken::UnlockChunks` (`max_values`: None, `max_size`: Some(675), added: 3150, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalUnbonding` (r:1 w:1)
+	/// Proof: `CreateToken::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn unstake_tokens() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `8602`
-		//  Minimum execution time: 40_000_000 picoseconds.
-		Weight::from_parts(40_000_000, 0)
+		//  Minimum execution time: 60_000_000 picoseconds.
+		Weight::from_parts(60_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 8602))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(12))
 	}
 	/// Storage: `CreateToken::StakedBalances` (r:1 w:0)
 	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalStaked` (r:1 w:0)
+	/// Proof: `CreateToken::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `CreateToken::LastRewardBlock` (r:1 w:1)
 	/// Proof: `CreateToken::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerBlock` (r:1 w:0)
+	/// Proof: `CreateToken::RewardPerBlock` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerShare` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerShare` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerSharePaid` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerSharePaid` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `CreateToken::PendingRewards` (r:1 w:1)
 	/// Proof: `CreateToken::PendingRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `CreateToken::Balances` (r:1 w:1)
@@ -139,11 +223,222 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `6046`
+		//  Estimated: `8578`
+		//  Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8578))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `CreateToken::Balances` (r:1 w:1)
+	/// Proof: `CreateToken::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalStaked` (r:1 w:0)
+	/// Proof: `CreateToken::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::LastRewardBlock` (r:1 w:1)
+	/// Proof: `CreateToken::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerBlock` (r:1 w:0)
+	/// Proof: `CreateToken::RewardPerBlock` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerShare` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerShare` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::VestingLocked` (r:1 w:0)
+	/// Proof: `CreateToken::VestingLocked` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::StakedBalances` (r:1 w:0)
+	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::UnlockChunks` (r:1 w:0)
+	/// Proof: `CreateToken::UnlockChunks` (`max_values`: None, `max_size`: Some(675), added: 3150, mode: `MaxEncodedLen`)
+	fn fund_reward_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3514`
+		//  Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3514))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CreateToken::TotalStaked` (r:1 w:0)
+	/// Proof: `CreateToken::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::LastRewardBlock` (r:1 w:1)
+	/// Proof: `CreateToken::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerBlock` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerBlock` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerShare` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerShare` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_reward_per_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1501`
+		//  Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1501))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CreateToken::UnlockChunks` (r:1 w:1)
+	/// Proof: `CreateToken::UnlockChunks` (`max_values`: None, `max_size`: Some(675), added: 3150, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::Balances` (r:1 w:1)
+	/// Proof: `CreateToken::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::StakedBalances` (r:1 w:0)
+	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	/// Storage: `CreateToken::TotalUnbonding` (r:1 w:1)
+	/// Proof: `CreateToken::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn withdraw_unbonded(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180 + n * (20 ±0)`
+		//  Estimated: `4764`
 		//  Minimum execution time: 35_000_000 picoseconds.
 		Weight::from_parts(35_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 6046))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 4764))
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CreateToken::UnlockChunks` (r:1 w:1)
+	/// Proof: `CreateToken::UnlockChunks` (`max_values`: None, `max_size`: Some(675), added: 3150, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::StakedBalances` (r:1 w:1)
+	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalStaked` (r:1 w:1)
+	/// Proof: `CreateToken::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::LastRewardBlock` (r:1 w:1)
+	/// Proof: `CreateToken::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerBlock` (r:1 w:0)
+	/// Proof: `CreateToken::RewardPerBlock` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerShare` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerShare` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPerSharePaid` (r:1 w:1)
+	/// Proof: `CreateToken::RewardPerSharePaid` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::PendingRewards` (r:1 w:1)
+	/// Proof: `CreateToken::PendingRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::GovernancePower` (r:1 w:1)
+	/// Proof: `CreateToken::GovernancePower` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalGovernancePower` (r:1 w:1)
+	/// Proof: `CreateToken::TotalGovernancePower` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	/// Storage: `CreateToken::TotalUnbonding` (r:1 w:1)
+	/// Proof: `CreateToken::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn rebond(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180 + n * (20 ±0)`
+		//  Estimated: `8602`
+		//  Minimum execution time: 55_000_000 picoseconds.
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8602))
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: `CreateToken::UnallocatedSupply` (r:1 w:1)
+	/// Proof: `CreateToken::UnallocatedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::Balances` (r:1 w:1)
+	/// Proof: `CreateToken::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn allocate_tokens() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3514`
+		//  Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3514))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CreateToken::Vesting` (r:1 w:1)
+	/// Proof: `CreateToken::Vesting` (`max_values`: None, `max_size`: Some(1330), added: 3805, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::VestingLocked` (r:0 w:1)
+	/// Proof: `CreateToken::VestingLocked` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn vest(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120 + n * (36 ±0)`
+		//  Estimated: `4795`
+		//  Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4795))
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(180_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CreateToken::Balances` (r:2 w:2)
+	/// Proof: `CreateToken::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::VestingLocked` (r:2 w:1)
+	/// Proof: `CreateToken::VestingLocked` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::StakedBalances` (r:1 w:0)
+	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::UnlockChunks` (r:1 w:0)
+	/// Proof: `CreateToken::UnlockChunks` (`max_values`: None, `max_size`: Some(675), added: 3150, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CreateToken::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::Vesting` (r:1 w:1)
+	/// Proof: `CreateToken::Vesting` (`max_values`: None, `max_size`: Some(1330), added: 3805, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 7]`.
+	/// Storage: `CreateToken::WalletCapExemptions` (r:1 w:0)
+	/// Proof: `CreateToken::WalletCapExemptions` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::WalletCapBasisOf` (r:1 w:0)
+	/// Proof: `CreateToken::WalletCapBasisOf` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::UnallocatedSupply` (r:1 w:0)
+	/// Proof: `CreateToken::UnallocatedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::RewardPool` (r:1 w:0)
+	/// Proof: `CreateToken::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalStaked` (r:1 w:0)
+	/// Proof: `CreateToken::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::TotalUnbonding` (r:1 w:0)
+	/// Proof: `CreateToken::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn vested_transfer(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `240 + n * (36 ±0)`
+		//  Estimated: `83788`
+		//  Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 83788))
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(46))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CreateToken::WalletCapExemptions` (r:1 w:1)
+	/// Proof: `CreateToken::WalletCapExemptions` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	fn add_wallet_cap_exemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1025`
+		//  Estimated: `2510`
+		//  Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2510))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CreateToken::WalletCapExemptions` (r:1 w:1)
+	/// Proof: `CreateToken::WalletCapExemptions` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	fn remove_wallet_cap_exemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1025`
+		//  Estimated: `2510`
+		//  Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2510))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CreateToken::WalletCapBasisOf` (r:0 w:1)
+	/// Proof: `CreateToken::WalletCapBasisOf` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_wallet_cap_basis() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
\ No newline at end of file
@@ -5,13 +5,20 @@ pub use pallet::*;
 pub mod weights;
 pub use weights::*;
 
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, LockableCurrency, LockIdentifier},
+        traits::{
+            fungible,
+            tokens::Preservation,
+            Currency, LockableCurrency, LockIdentifier,
+        },
     };
+    use codec::DecodeWithMemTracking;
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::{Zero, StaticLookup};
 
@@ -28,11 +35,23 @@ pub mod pallet {
         type MaxActiveProposals: Get<u32>;
         type TreasuryAccount: Get<Self::AccountId>;
         type GovernanceLockId: Get<LockIdentifier>;
+        /// The currency protocol fees are paid in, swept into the treasury by the fee engine.
+        type FeeCurrency: fungible::Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
     }
 
     pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
     pub type LockIdFor = LockIdentifier;
 
+    /// The treasury balance a proposal is paid from.
+    #[derive(Clone, Copy, Encode, Decode, DecodeWithMemTracking, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum TreasuryFunds {
+        /// Native currency, see [`TreasuryBalance`].
+        #[default]
+        Native,
+        /// Protocol fees in `FeeCurrency`, see [`FeeTreasuryBalance`].
+        Fees,
+    }
+
     #[derive(Clone, Encode, Decode, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
     pub struct Proposal<T: Config> {
@@ -47,13 +66,17 @@ pub mod pallet {
         pub end_block: BlockNumberFor<T>,
         pub executed: bool,
         pub cancelled: bool,
+        pub funds: TreasuryFunds,
     }
 
     pub type Vote = u8;
     pub const VOTE_YES: Vote = 1;
     pub const VOTE_NO: Vote = 0;
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::storage]
@@ -76,6 +99,11 @@ pub mod pallet {
     #[pallet::getter(fn treasury_balance)]
     pub type TreasuryBalance<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// Protocol fees in `FeeCurrency` held by `TreasuryAccount` and spendable by proposals.
+    #[pallet::storage]
+    #[pallet::getter(fn fee_treasury_balance)]
+    pub type FeeTreasuryBalance<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn member_voting_power)]
     pub type MemberVotingPower<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
@@ -94,6 +122,8 @@ pub mod pallet {
         TreasuryFunded { amount: BalanceOf<T> },
         MemberAdded { account: T::AccountId, voting_power: u32 },
         MemberRemoved { account: T::AccountId },
+        /// Protocol fees were swept into the treasury. [amount]
+        FeeTreasuryFunded { amount: BalanceOf<T> },
     }
 
     #[pallet::error]
@@ -120,6 +150,7 @@ pub mod pallet {
             description: BoundedVec<u8, ConstU32<1024>>,
             amount: BalanceOf<T>,
             recipient: <<T as frame_system::Config>::Lookup as StaticLookup>::Source,
+            funds: TreasuryFunds,
         ) -> DispatchResult {
             let proposer = ensure_signed(origin)?;
             let recipient = T::Lookup::lookup(recipient)?;
@@ -166,6 +197,7 @@ pub mod pallet {
                 end_block,
                 executed: false,
                 cancelled: false,
+                funds,
             };
 
             Proposals::<T>::insert(proposal_id, proposal);
@@ -249,14 +281,30 @@ pub mod pallet {
             ensure!(total_votes >= T::MinVotes::get(), Error::<T>::InsufficientVotes);
 
             if proposal.yes_votes > proposal.no_votes {
-                let treasury_balance = TreasuryBalance::<T>::get();
-                ensure!(treasury_balance >= proposal.amount, Error::<T>::TreasuryInsufficientFunds);
-
                 // Transfer funds from treasury to recipient
                 let recipient = proposal.recipient.clone();
                 let amount = proposal.amount;
-                TreasuryBalance::<T>::mutate(|balance| *balance -= amount);
-                T::Currency::deposit_creating(&recipient, amount);
+                match proposal.funds {
+                    TreasuryFunds::Native => {
+                        let treasury_balance = TreasuryBalance::<T>::get();
+                        ensure!(treasury_balance >= amount, Error::<T>::TreasuryInsufficientFunds);
+
+                        TreasuryBalance::<T>::mutate(|balance| *balance -= amount);
+                        T::Currency::deposit_creating(&recipient, amount);
+                    }
+                    TreasuryFunds::Fees => {
+                        let treasury_balance = FeeTreasuryBalance::<T>::get();
+                        ensure!(treasury_balance >= amount, Error::<T>::TreasuryInsufficientFunds);
+
+                        <T::FeeCurrency as fungible::Mutate<_>>::transfer(
+                            &T::TreasuryAccount::get(),
+                            &recipient,
+                            amount,
+                            Preservation::Expendable,
+                        )?;
+                        FeeTreasuryBalance::<T>::mutate(|balance| *balance -= amount);
+                    }
+                }
 
                 proposal.executed = true;
                 Proposals::<T>::insert(proposal_id, proposal);
@@ -303,18 +351,11 @@ pub mod pallet {
         ) -> DispatchResult {
             let funder = ensure_signed(origin)?;
 
-            T::Currency::withdraw(
+            Self::deposit_into_treasury(
                 &funder,
                 amount,
-                frame_support::traits::WithdrawReasons::TRANSFER,
                 frame_support::traits::ExistenceRequirement::KeepAlive,
-            )?;
-
-            TreasuryBalance::<T>::mutate(|balance| *balance += amount);
-
-            Self::deposit_event(Event::TreasuryFunded { amount });
-
-            Ok(())
+            )
         }
 
         #[pallet::weight(T::WeightInfo::add_member())]
@@ -380,6 +421,46 @@ pub mod pallet {
         pub fn get_total_voting_power() -> u32 {
             TotalVotingPower::<T>::get()
         }
+
+        /// Move `amount` from `source` into the treasury, making it spendable by proposals.
+        pub fn deposit_into_treasury(
+            source: &T::AccountId,
+            amount: BalanceOf<T>,
+            existence_requirement: frame_support::traits::ExistenceRequirement,
+        ) -> DispatchResult {
+            T::Currency::withdraw(
+                source,
+                amount,
+                frame_support::traits::WithdrawReasons::TRANSFER,
+                existence_requirement,
+            )?;
+
+            TreasuryBalance::<T>::mutate(|balance| *balance += amount);
+
+            Self::deposit_event(Event::TreasuryFunded { amount });
+
+            Ok(())
+        }
+
+        /// Move `amount` of protocol fees from `source` to the treasury account, making them
+        /// spendable by [`TreasuryFunds::Fees`] proposals.
+        pub fn deposit_fees_into_treasury(
+            source: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            <T::FeeCurrency as fungible::Mutate<_>>::transfer(
+                source,
+                &T::TreasuryAccount::get(),
+                amount,
+                Preservation::Expendable,
+            )?;
+
+            FeeTreasuryBalance::<T>::mutate(|balance| *balance += amount);
+
+            Self::deposit_event(Event::FeeTreasuryFunded { amount });
+
+            Ok(())
+        }
     }
 }
 
@@ -485,6 +566,7 @@ mod tests {
         type MaxActiveProposals = MaxActiveProposals;
         type TreasuryAccount = TreasuryAccount;
         type GovernanceLockId = GovernanceLockId;
+        type FeeCurrency = Balances;
     }
 
     fn new_test_ext() -> sp_io::TestExternalities {
@@ -510,7 +592,8 @@ mod tests {
                 RuntimeOrigin::signed(1),
                 b"Test proposal".to_vec(),
                 100,
-                2
+                2,
+                TreasuryFunds::Native
             ));
 
             let proposal = Dao::get_proposal(1).unwrap();
@@ -533,7 +616,8 @@ mod tests {
                 RuntimeOrigin::signed(1),
                 b"Test proposal".to_vec(),
                 100,
-                3
+                3,
+                TreasuryFunds::Native
             ));
 
             // Vote
@@ -0,0 +1,82 @@
+//! Storage migrations for the DAO pallet.
+
+/// Record which treasury balance each proposal is paid from.
+pub mod v1 {
+    use crate::{BalanceOf, Config, Pallet, Proposal, Proposals, TreasuryFunds};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// `Proposal` as it was stored in version 0, before proposals could spend protocol fees.
+    #[derive(Encode, Decode)]
+    pub struct OldProposal<T: Config> {
+        pub id: u32,
+        pub proposer: T::AccountId,
+        pub description: BoundedVec<u8, ConstU32<1024>>,
+        pub amount: BalanceOf<T>,
+        pub recipient: T::AccountId,
+        pub yes_votes: u32,
+        pub no_votes: u32,
+        pub start_block: BlockNumberFor<T>,
+        pub end_block: BlockNumberFor<T>,
+        pub executed: bool,
+        pub cancelled: bool,
+    }
+
+    /// Pays every existing proposal from the native treasury, the only one version 0 had.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Proposals::<T>::translate::<OldProposal<T>, _>(|_, old| {
+                translated += 1;
+                Some(Proposal {
+                    id: old.id,
+                    proposer: old.proposer,
+                    description: old.description,
+                    amount: old.amount,
+                    recipient: old.recipient,
+                    yes_votes: old.yes_votes,
+                    no_votes: old.no_votes,
+                    start_block: old.start_block,
+                    end_block: old.end_block,
+                    executed: old.executed,
+                    cancelled: old.cancelled,
+                    funds: TreasuryFunds::Native,
+                })
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Proposals::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+            ensure!(
+                Proposals::<T>::iter_values().count() as u32 == count,
+                "proposals lost in migration"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`InnerMigrateV0ToV1`] guarded by the pallet's storage version.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -11,6 +11,12 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 pub mod weights;
 pub use weights::*;
 
@@ -19,10 +25,12 @@ pub mod pallet {
     use super::*;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, ExistenceRequirement, WithdrawReasons},
+        traits::Currency,
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, Zero, IntegerSquareRoot};
+    use sp_runtime::traits::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, Zero, IntegerSquareRoot, Saturating};
+    use sp_runtime::{FixedPointNumber, FixedU128};
+    use pallet_oracle::PriceProvider;
     use sp_std::vec::Vec;
 
     /// The pallet's configuration trait.
@@ -54,6 +62,13 @@ pub mod pallet {
         /// The maximum slippage tolerance (in basis points).
         #[pallet::constant]
         type MaxSlippageBps: Get<u32>;
+
+        /// Source of external reference prices, keyed by token symbol.
+        type PriceProvider: PriceProvider<BoundedVec<u8, ConstU32<32>>>;
+
+        /// The maximum shortfall of a trade against the oracle reference price (in basis points).
+        #[pallet::constant]
+        type MaxPriceDeviationBps: Get<u32>;
     }
 
     /// Balance type for this pallet.
@@ -286,9 +301,6 @@ pub mod pallet {
             let pool_id = NextPoolId::<T>::get();
             NextPoolId::<T>::put(pool_id + 1);
 
-            // TODO: Collect protocol fee for pool creation
-            // let total_liquidity_value = initial_liquidity_a.checked_add(&initial_liquidity_b)?;
-
             // Calculate initial LP tokens (geometric mean)
             let initial_lp_tokens = initial_liquidity_a.checked_mul(&initial_liquidity_b)
                 .ok_or(Error::<T>::Overflow)?
@@ -444,9 +456,18 @@ pub mod pallet {
             // Check slippage
             ensure!(amount_out >= min_amount_out, Error::<T>::SlippageExceeded);
 
-            // TODO: Collect protocol fee for DEX trading
-            // let protocol_fee = T::FeeEngine::get_fee(&pallet_fee_engine::TX_TYPE_DEX_TRADING);
-            // T::FeeEngine::collect_fee(&trader, pallet_fee_engine::TX_TYPE_DEX_TRADING, protocol_fee);
+            // Check against the oracle reference price
+            if let Some(reference_price) = Self::get_reference_price(&token_in, &token_out) {
+                let expected_out = reference_price.saturating_mul_int(amount_in_with_fee);
+                let max_shortfall = expected_out.checked_mul(&T::MaxPriceDeviationBps::get().into())
+                    .ok_or(Error::<T>::Overflow)?
+                    .checked_div(&10_000u32.into())
+                    .ok_or(Error::<T>::Underflow)?;
+                ensure!(
+                    amount_out >= expected_out.saturating_sub(max_shortfall),
+                    Error::<T>::PriceImpactTooHigh
+                );
+            }
 
             // Update pool reserves
             let new_reserve_in = reserve_in.checked_add(&amount_in)
@@ -568,6 +589,16 @@ pub mod pallet {
     }
 
     impl<T: Config> Pallet<T> {
+        /// Get the oracle price of `token_in` denominated in `token_out`, if both are priced.
+        pub fn get_reference_price(
+            token_in: &BoundedVec<u8, ConstU32<32>>,
+            token_out: &BoundedVec<u8, ConstU32<32>>,
+        ) -> Option<FixedU128> {
+            let price_in = T::PriceProvider::get_price(token_in)?;
+            let price_out = T::PriceProvider::get_price(token_out)?;
+            price_in.checked_div(&price_out)
+        }
+
         /// Get the current price of a token pair from AMM pool.
         pub fn get_amm_price(pool_id: PoolId) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
             let pool = Pools::<T>::get(pool_id)?;
@@ -0,0 +1,107 @@
+use crate as pallet_dex;
+use frame_support::{
+    derive_impl,
+    traits::{ConstU32, ConstU64},
+    BoundedVec,
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{BuildStorage, FixedU128};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Oracle = pallet_oracle::Pallet<Test>;
+
+    #[runtime::pallet_index(3)]
+    pub type Dex = pallet_dex::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+impl pallet_oracle::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type OracleKey = BoundedVec<u8, ConstU32<32>>;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type MaxFeeders = ConstU32<4>;
+    type MinimumFeeds = ConstU32<1>;
+    type MaxPriceAge = ConstU64<10>;
+}
+
+impl pallet_dex::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type WeightInfo = pallet_dex::weights::SubstrateWeight<Test>;
+    type TradingFeeBps = ConstU32<30>;
+    type LpFeeBps = ConstU32<25>;
+    type MinLiquidity = ConstU64<MIN_LIQUIDITY>;
+    type MaxSlippageBps = ConstU32<500>;
+    type PriceProvider = Oracle;
+    type MaxPriceDeviationBps = ConstU32<100>;
+}
+
+pub const ALICE: u64 = 1;
+pub const TRADER: u64 = 2;
+pub const FEEDER: u64 = 5;
+
+pub const MIN_LIQUIDITY: u64 = 1_000;
+// Liquidity of each side of the `FI-USDT` pool created at genesis.
+pub const POOL_LIQUIDITY: u64 = 1_000_000;
+pub const POOL: u64 = 0;
+
+pub fn token(symbol: &[u8]) -> BoundedVec<u8, ConstU32<32>> {
+    symbol.to_vec().try_into().unwrap()
+}
+
+// Publish the oracle price of a token in FI.
+pub fn set_price(symbol: &[u8], price: FixedU128) {
+    Oracle::feed_price(RuntimeOrigin::signed(FEEDER), token(symbol), price).unwrap();
+}
+
+// Build genesis storage with a balanced `FI-USDT` pool created by `ALICE` and `FEEDER`
+// registered with the oracle.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Dex::create_pool(RuntimeOrigin::signed(ALICE), token(b"FI"), token(b"USDT"), POOL_LIQUIDITY, POOL_LIQUIDITY)
+            .unwrap();
+        Oracle::add_feeder(RuntimeOrigin::root(), FEEDER).unwrap();
+    });
+    ext
+}
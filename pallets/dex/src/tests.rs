@@ -0,0 +1,107 @@
+use crate::{mock::*, Error, Event, TokenPair};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::FixedU128;
+
+fn trade(token_in: &[u8], amount_in: u64) -> sp_runtime::DispatchResult {
+    Dex::amm_trade(RuntimeOrigin::signed(TRADER), POOL, token(token_in), amount_in, 0)
+}
+
+fn reserves() -> (u64, u64) {
+    Dex::get_amm_price(POOL).unwrap()
+}
+
+#[test]
+fn pools_are_created_once_per_pair() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(reserves(), (POOL_LIQUIDITY, POOL_LIQUIDITY));
+        assert_eq!(Dex::get_lp_balance(POOL, ALICE), POOL_LIQUIDITY);
+
+        assert_noop!(
+            Dex::create_pool(RuntimeOrigin::signed(ALICE), token(b"FI"), token(b"USDT"), MIN_LIQUIDITY, MIN_LIQUIDITY),
+            Error::<Test>::PoolAlreadyExists
+        );
+        assert_noop!(
+            Dex::create_pool(RuntimeOrigin::signed(ALICE), token(b"FI"), token(b"DAI"), MIN_LIQUIDITY - 1, MIN_LIQUIDITY),
+            Error::<Test>::AmountBelowMinimum
+        );
+    });
+}
+
+#[test]
+fn trades_follow_the_constant_product_without_oracle_prices() {
+    new_test_ext().execute_with(|| {
+        // Without reference prices even a large trade only moves along the curve
+        assert_ok!(trade(b"FI", 100_000));
+        // 0.3% of the input is kept as fee: 1_000_000 * 99_700 / 1_099_700
+        System::assert_last_event(
+            Event::AmmTrade {
+                pool_id: POOL,
+                trader: TRADER,
+                token_in: token(b"FI"),
+                token_out: token(b"USDT"),
+                amount_in: 100_000,
+                amount_out: 90_661,
+                fee: 300,
+            }
+            .into(),
+        );
+        assert_eq!(reserves(), (POOL_LIQUIDITY + 100_000, POOL_LIQUIDITY - 90_661));
+        let pair: TokenPair = b"FI-USDT".to_vec().try_into().unwrap();
+        assert_eq!(Dex::get_trading_volume(pair), 100_000);
+    });
+}
+
+#[test]
+fn trades_respect_the_minimum_output() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Dex::amm_trade(RuntimeOrigin::signed(TRADER), POOL, token(b"FI"), 1_000, 997),
+            Error::<Test>::SlippageExceeded
+        );
+        assert_noop!(trade(b"DAI", 1_000), Error::<Test>::InvalidTokenPair);
+        assert_noop!(
+            Dex::amm_trade(RuntimeOrigin::signed(TRADER), 1, token(b"FI"), 1_000, 0),
+            Error::<Test>::PoolNotFound
+        );
+    });
+}
+
+#[test]
+fn trades_deviating_from_the_oracle_price_are_rejected() {
+    new_test_ext().execute_with(|| {
+        set_price(b"FI", FixedU128::from_u32(1));
+        set_price(b"USDT", FixedU128::from_u32(1));
+        assert_eq!(Dex::get_reference_price(&token(b"FI"), &token(b"USDT")), Some(FixedU128::from_u32(1)));
+
+        // 997 FI after fees buy 996 USDT, within 1% of the oracle price
+        assert_ok!(trade(b"FI", 1_000));
+
+        // The price impact of a large trade exceeds `MaxPriceDeviationBps`
+        assert_noop!(trade(b"FI", 100_000), Error::<Test>::PriceImpactTooHigh);
+        assert_noop!(trade(b"USDT", 100_000), Error::<Test>::PriceImpactTooHigh);
+    });
+}
+
+#[test]
+fn pools_off_the_oracle_price_only_trade_in_the_favourable_direction() {
+    new_test_ext().execute_with(|| {
+        // The oracle values FI at 1.1 USDT while the pool trades at par
+        set_price(b"FI", FixedU128::from_rational(110, 100));
+        set_price(b"USDT", FixedU128::from_u32(1));
+
+        assert_noop!(trade(b"FI", 1_000), Error::<Test>::PriceImpactTooHigh);
+        assert_ok!(trade(b"USDT", 1_000));
+    });
+}
+
+#[test]
+fn stale_oracle_prices_are_ignored() {
+    new_test_ext().execute_with(|| {
+        set_price(b"FI", FixedU128::from_rational(110, 100));
+        set_price(b"USDT", FixedU128::from_u32(1));
+
+        System::set_block_number(12);
+        assert_eq!(Dex::get_reference_price(&token(b"FI"), &token(b"USDT")), None);
+        assert_ok!(trade(b"FI", 1_000));
+    });
+}
@@ -0,0 +1,168 @@
+//! RPC interface for the fee engine pallet.
+//!
+//! Exposes the [`FeeEngineRuntimeApi`] under the `feeEngine_*` namespace so wallets can show the
+//! fixed FI fee of a transaction before it is signed.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{error::ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+
+pub use pallet_fee_engine_runtime_api::{EraIndex, FeeCategory, FeeEngineApi as FeeEngineRuntimeApi};
+
+#[rpc(client, server)]
+pub trait FeeEngineApi<BlockHash, AccountId, ResponseType> {
+    /// The fixed fee of `category`, or `None` if no fee is registered for it.
+    #[method(name = "feeEngine_fee")]
+    fn fee(&self, category: FeeCategory, at: Option<BlockHash>) -> RpcResult<Option<ResponseType>>;
+
+    /// Every fee category with a registered fee, and its fixed fee.
+    #[method(name = "feeEngine_feeSchedule")]
+    fn fee_schedule(&self, at: Option<BlockHash>) -> RpcResult<Vec<(FeeCategory, ResponseType)>>;
+
+    /// The fixed fee `who` pays for the SCALE-encoded `call` after its staking discount.
+    #[method(name = "feeEngine_callFee")]
+    fn call_fee(
+        &self,
+        who: AccountId,
+        encoded_call: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<(FeeCategory, ResponseType)>>;
+
+    /// The revenue era fees are currently booked into.
+    #[method(name = "feeEngine_currentRevenueEra")]
+    fn current_revenue_era(&self, at: Option<BlockHash>) -> RpcResult<EraIndex>;
+
+    /// Revenue of `era` by fee category, empty if the era is unknown or pruned.
+    #[method(name = "feeEngine_eraRevenue")]
+    fn era_revenue(&self, era: EraIndex, at: Option<BlockHash>) -> RpcResult<Vec<(FeeCategory, ResponseType)>>;
+}
+
+/// Provides RPC methods to query fixed fees.
+pub struct FeeEngine<C, Block, Balance, Call> {
+    client: Arc<C>,
+    _marker: PhantomData<(Block, Balance, Call)>,
+}
+
+impl<C, Block, Balance, Call> FeeEngine<C, Block, Balance, Call> {
+    /// Creates a new instance of the FeeEngine RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to the runtime failed.
+    RuntimeError,
+    /// The call could not be decoded.
+    DecodeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::DecodeError => 2,
+        }
+    }
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::RuntimeError.into(), message, Some(format!("{:?}", e)))
+}
+
+fn into_number_or_hex<Balance>(balance: Balance) -> RpcResult<NumberOrHex>
+where
+    Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>,
+{
+    balance.try_into().map_err(|_| {
+        ErrorObject::owned(
+            Error::RuntimeError.into(),
+            "Amount does not fit into a `NumberOrHex`.",
+            Some(format!("{}", balance)),
+        )
+    })
+}
+
+impl<C, Block, AccountId, Balance, Call>
+    FeeEngineApiServer<<Block as BlockT>::Hash, AccountId, NumberOrHex> for FeeEngine<C, Block, Balance, Call>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: FeeEngineRuntimeApi<Block, AccountId, Balance, Call>,
+    AccountId: Codec + Send + Sync + 'static,
+    Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+    Call: Codec + Send + Sync + 'static,
+{
+    fn fee(&self, category: FeeCategory, at: Option<Block::Hash>) -> RpcResult<Option<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let fee = api
+            .fee(at_hash, category)
+            .map_err(|e| runtime_error("Unable to query fee.", e))?;
+        fee.map(into_number_or_hex).transpose()
+    }
+
+    fn fee_schedule(&self, at: Option<Block::Hash>) -> RpcResult<Vec<(FeeCategory, NumberOrHex)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let schedule = api
+            .fee_schedule(at_hash)
+            .map_err(|e| runtime_error("Unable to query fee schedule.", e))?;
+        schedule
+            .into_iter()
+            .map(|(category, fee)| Ok((category, into_number_or_hex(fee)?)))
+            .collect()
+    }
+
+    fn call_fee(
+        &self,
+        who: AccountId,
+        encoded_call: Bytes,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<(FeeCategory, NumberOrHex)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let call = Call::decode(&mut &*encoded_call).map_err(|e| {
+            ErrorObject::owned(Error::DecodeError.into(), "Unable to decode call.", Some(format!("{:?}", e)))
+        })?;
+        let quote = api
+            .call_fee(at_hash, who, call)
+            .map_err(|e| runtime_error("Unable to query call fee.", e))?;
+        quote.map(|(category, fee)| Ok((category, into_number_or_hex(fee)?))).transpose()
+    }
+
+    fn current_revenue_era(&self, at: Option<Block::Hash>) -> RpcResult<EraIndex> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.current_revenue_era(at_hash)
+            .map_err(|e| runtime_error("Unable to query current revenue era.", e))
+    }
+
+    fn era_revenue(&self, era: EraIndex, at: Option<Block::Hash>) -> RpcResult<Vec<(FeeCategory, NumberOrHex)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let revenue = api
+            .era_revenue(at_hash, era)
+            .map_err(|e| runtime_error("Unable to query era revenue.", e))?;
+        revenue
+            .into_iter()
+            .map(|(category, amount)| Ok((category, into_number_or_hex(amount)?)))
+            .collect()
+    }
+}
@@ -0,0 +1,38 @@
+//! Runtime API definition for the fee engine pallet.
+//!
+//! Lets wallets quote the fixed FI fee of a transaction before signing it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_fee_engine::{EraIndex, FeeCategory};
+
+sp_api::decl_runtime_apis! {
+    /// Quotes the fixed fees levied by `pallet_fee_engine`.
+    pub trait FeeEngineApi<AccountId, Balance, Call> where
+        AccountId: Codec,
+        Balance: Codec,
+        Call: Codec,
+    {
+        /// The fixed fee of `category`, or `None` if no fee is registered for it.
+        fn fee(category: FeeCategory) -> Option<Balance>;
+
+        /// Every fee category with a registered fee, and its fixed fee.
+        fn fee_schedule() -> Vec<(FeeCategory, Balance)>;
+
+        /// The fixed fee `who` pays for `call` after its staking discount.
+        ///
+        /// `None` if the call only pays gas or its category has no registered fee.
+        fn call_fee(who: AccountId, call: Call) -> Option<(FeeCategory, Balance)>;
+
+        /// The revenue era fees are currently booked into.
+        fn current_revenue_era() -> EraIndex;
+
+        /// Revenue of `era` by fee category, empty if the era is unknown or pruned.
+        fn era_revenue(era: EraIndex) -> Vec<(FeeCategory, Balance)>;
+    }
+}
@@ -0,0 +1,148 @@
+//! Transaction extension that levies the fixed protocol fees.
+//!
+//! [`ChargeFixedFee`] looks up the [`FeeCategory`] of every signed call through
+//! [`Config::CallToFeeType`] and collects the matching entry of [`crate::FixedFees`] before the call
+//! is dispatched, minus the signer's staking discount. Calls without a fee type only pay gas.
+//! Transactions whose category has no registered fee, or whose signer cannot cover it, are
+//! rejected from the pool.
+
+use crate::{BalanceOf, Config, FeeCategory, Pallet, WeightInfo};
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+    traits::{
+        fungible::Inspect,
+        tokens::{Fortitude, Preservation},
+    },
+    weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{
+        AsSystemOriginSigner, DispatchInfoOf, DispatchOriginOf, Dispatchable, Implication,
+        Saturating, TransactionExtension, ValidateResult, Zero,
+    },
+    transaction_validity::{
+        InvalidTransaction, TransactionSource, TransactionValidityError, ValidTransaction,
+    },
+};
+
+/// Maps a runtime call to the fixed fee it has to pay, if any.
+///
+/// The extension only sees the outer call of a transaction. Implementations must therefore look
+/// through every call that dispatches another call, such as `sudo`, `utility::batch` or
+/// `proxy::proxy`, or the fixed fee of the inner call can be avoided by wrapping it.
+pub trait CallToFeeType<Call> {
+    fn fee_type(call: &Call) -> Option<FeeCategory>;
+}
+
+impl<Call> CallToFeeType<Call> for () {
+    fn fee_type(_call: &Call) -> Option<FeeCategory> {
+        None
+    }
+}
+
+/// Collects the fixed fee of the call from its signer before dispatch.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeFixedFee<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> ChargeFixedFee<T> {
+    /// Create new `TransactionExtension` to charge fixed fees.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> Default for ChargeFixedFee<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> core::fmt::Debug for ChargeFixedFee<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "ChargeFixedFee")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+/// The fee to collect in `prepare`, as determined by `validate`.
+pub enum Val<T: Config> {
+    Charge {
+        who: T::AccountId,
+        category: FeeCategory,
+        fee: BalanceOf<T>,
+    },
+    NoCharge,
+}
+
+impl<T> TransactionExtension<T::RuntimeCall> for ChargeFixedFee<T>
+where
+    T: Config + Send + Sync,
+    T::RuntimeCall: Dispatchable,
+    <T::RuntimeCall as Dispatchable>::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + Clone,
+{
+    const IDENTIFIER: &'static str = "ChargeFixedFee";
+    type Implicit = ();
+    type Val = Val<T>;
+    type Pre = ();
+
+    fn weight(&self, call: &T::RuntimeCall) -> Weight {
+        if T::CallToFeeType::fee_type(call).is_some() {
+            T::WeightInfo::charge_fixed_fee()
+        } else {
+            Weight::zero()
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Implication,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let Some(who) = origin.as_system_origin_signer() else {
+            return Ok((ValidTransaction::default(), Val::NoCharge, origin));
+        };
+        let Some(category) = T::CallToFeeType::fee_type(call) else {
+            return Ok((ValidTransaction::default(), Val::NoCharge, origin));
+        };
+
+        let fee = Pallet::<T>::get_fee(&category).map_err(|_| InvalidTransaction::Payment)?;
+        if fee.is_zero() {
+            return Ok((ValidTransaction::default(), Val::NoCharge, origin));
+        }
+        let due = fee.saturating_sub(Pallet::<T>::staking_discount(who, fee));
+        if T::Currency::reducible_balance(who, Preservation::Preserve, Fortitude::Polite) < due {
+            return Err(InvalidTransaction::Payment.into());
+        }
+
+        let val = Val::Charge { who: who.clone(), category, fee };
+        Ok((ValidTransaction::default(), val, origin))
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        _origin: &DispatchOriginOf<T::RuntimeCall>,
+        _call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if let Val::Charge { who, category, fee } = val {
+            Pallet::<T>::collect_fee(&who, category, fee)
+                .map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+        }
+        Ok(())
+    }
+}
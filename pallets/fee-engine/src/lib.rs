@@ -1,22 +1,169 @@
 //! # Fee Engine Pallet
 //!
 //! This pallet implements the fixed fee system for the CREATEFI blockchain.
-//! It enforces predictable fees for all operations and handles fee distribution
-//! between the founder (15%) and DAO treasury (85%).
+//! It enforces predictable fees for all operations and distributes them between a configurable
+//! set of beneficiaries, by default the founder (15%) and DAO treasury (85%).
+//!
+//! Fixed fees are levied on signed calls by the [`ChargeFixedFee`] transaction extension.
+//! Collected fees are held by the pallet account (derived from [`Config::PalletId`]) until each
+//! beneficiary withdraws its accrued share.
+//!
+//! The fee table is controlled by [`Config::UpdateOrigin`]. Besides immediate updates, a new
+//! fee schedule can be announced to activate at a future block, giving integrators notice of
+//! at least [`Config::MinAnnouncementPeriod`] blocks. Each network sets its initial fee table
+//! through the genesis config.
+//!
+//! Payers with enough stake, as reported by [`Config::StakeProvider`], get a discount on fixed
+//! fees according to the governance-set [`StakingDiscounts`].
+//!
+//! Every [`Config::SweepPeriod`] blocks the fees accrued by [`Config::DaoTreasuryAccount`] are
+//! swept into [`Config::DaoTreasury`] instead of waiting for a withdrawal.
+//!
+//! Revenue is also bucketed per fee category into eras of [`Config::RevenueEraLength`] blocks.
+//! The last [`Config::RevenueRetention`] eras are kept in [`EraRevenue`], older ones are pruned
+//! when an era closes.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+
+/// Categories of operations that pay a fixed fee.
+///
+/// The explicit indices match the `u8` identifiers used before this enum was introduced, see
+/// [`migrations::v1`].
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq,
+    PartialOrd, Ord, RuntimeDebug, Serialize, Deserialize,
+)]
+pub enum FeeCategory {
+    #[codec(index = 0)]
+    GasFee,
+    #[codec(index = 1)]
+    BridgeSmall,
+    #[codec(index = 2)]
+    BridgeMedium,
+    #[codec(index = 3)]
+    BridgeLarge,
+    #[codec(index = 4)]
+    NftBridge,
+    #[codec(index = 5)]
+    DexTrading,
+    #[codec(index = 6)]
+    PoolSmall,
+    #[codec(index = 7)]
+    PoolMedium,
+    #[codec(index = 8)]
+    PoolLarge,
+    #[codec(index = 9)]
+    PoolOperations,
+    #[codec(index = 10)]
+    TokenCreation,
+    #[codec(index = 11)]
+    NftMintingSmall,
+    #[codec(index = 12)]
+    NftMintingMedium,
+    #[codec(index = 13)]
+    NftMintingLarge,
+    #[codec(index = 14)]
+    NftMintingXLarge,
+    #[codec(index = 15)]
+    VaultCreation,
+    #[codec(index = 16)]
+    GovernanceProposal,
+    /// Mint and redeem fees of the FI peg stability module.
+    #[codec(index = 17)]
+    PegStability,
+}
+
+/// Groups of fee categories whose tier is chosen by the size of the operation.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq,
+    PartialOrd, Ord, RuntimeDebug, Serialize, Deserialize,
+)]
+pub enum FeeTierGroup {
+    /// Bridge transfers, tiered by transferred value.
+    #[codec(index = 0)]
+    Bridge,
+    /// Pool creation, tiered by pool liquidity.
+    #[codec(index = 1)]
+    Pool,
+    /// NFT minting, tiered by payload size in bytes.
+    #[codec(index = 2)]
+    NftMinting,
+}
+
+impl FeeTierGroup {
+    /// The fee categories of this group, from the smallest to the largest tier.
+    pub fn tiers(self) -> &'static [FeeCategory] {
+        match self {
+            Self::Bridge => &[FeeCategory::BridgeSmall, FeeCategory::BridgeMedium, FeeCategory::BridgeLarge],
+            Self::Pool => &[FeeCategory::PoolSmall, FeeCategory::PoolMedium, FeeCategory::PoolLarge],
+            Self::NftMinting => &[
+                FeeCategory::NftMintingSmall,
+                FeeCategory::NftMintingMedium,
+                FeeCategory::NftMintingLarge,
+                FeeCategory::NftMintingXLarge,
+            ],
+        }
+    }
+}
+
 /// Trait for other pallets to interact with the fee engine.
 pub trait FeeEngineInterface<AccountId, Balance> {
-    fn collect_fee(payer: &AccountId, transaction_type: u8, fee_amount: Balance) -> Result<(), &'static str>;
-    fn get_fee(transaction_type: &u8) -> Balance;
-    fn check_fee(transaction_type: &u8, fee_paid: Balance) -> bool;
+    fn collect_fee(payer: &AccountId, category: FeeCategory, fee_amount: Balance) -> Result<(), &'static str>;
+    /// Record a fee that was settled outside of the fee engine's `Currency`.
+    fn record_fee(payer: &AccountId, category: FeeCategory, fee_amount: Balance);
+    /// Distribute a fee that was already paid into [`FeeEngineInterface::account_id`].
+    fn credit_fee(payer: &AccountId, category: FeeCategory, fee_amount: Balance) -> Result<(), &'static str>;
+    /// The account holding collected fees until they are withdrawn.
+    fn account_id() -> AccountId;
+    /// The fixed fee of a category, or an error if no fee is registered for it.
+    fn get_fee(category: &FeeCategory) -> Result<Balance, &'static str>;
+    fn check_fee(category: &FeeCategory, fee_paid: Balance) -> bool;
+    /// The tier of `group` that applies to an operation of size `value`, and its fixed fee.
+    fn resolve_fee(group: FeeTierGroup, value: Balance) -> Result<(FeeCategory, Balance), &'static str>;
+}
+
+/// Interface for reading how much an account has staked.
+pub trait StakeProvider<AccountId, Balance> {
+    /// The amount `who` currently has staked.
+    fn staked_balance(who: &AccountId) -> Balance;
+}
+
+impl<AccountId, Balance: Default> StakeProvider<AccountId, Balance> for () {
+    fn staked_balance(_who: &AccountId) -> Balance {
+        Balance::default()
+    }
+}
+
+/// Destination the DAO treasury's accrued fees are swept into.
+pub trait TreasuryDeposit<AccountId, Balance> {
+    /// Move `amount` out of `source` into the treasury.
+    fn deposit(source: &AccountId, amount: Balance) -> sp_runtime::DispatchResult;
+}
+
+impl<AccountId, Balance> TreasuryDeposit<AccountId, Balance> for () {
+    fn deposit(_source: &AccountId, _amount: Balance) -> sp_runtime::DispatchResult {
+        Err(sp_runtime::DispatchError::Other("No treasury to sweep fees into"))
+    }
 }
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod extension;
+pub mod migrations;
+pub use extension::{CallToFeeType, ChargeFixedFee};
 pub mod weights;
 pub use weights::*;
 
@@ -24,11 +171,18 @@ pub use weights::*;
 pub mod pallet {
     use super::*;
     use frame_support::{
-    pallet_prelude::*,
-    traits::{Currency, Hooks},
-};
-use frame_system::pallet_prelude::*;
-use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero, UniqueSaturatedFrom};
+        pallet_prelude::*,
+        traits::{
+            fungible::{Inspect, Mutate},
+            tokens::Preservation,
+            Hooks,
+        },
+        PalletId,
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{AccountIdConversion, CheckedAdd, Saturating, Zero};
+    use sp_runtime::Permill;
+    use sp_std::{vec, vec::Vec};
 
     /// The pallet's configuration trait.
     #[pallet::config]
@@ -36,105 +190,288 @@ use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero, UniqueSaturatedFrom};
         /// The overarching runtime event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         
-        /// The currency type for handling balances.
-        type Currency: Currency<Self::AccountId>;
+        /// The currency fees are charged and paid out in. The fee table is denominated in it.
+        type Currency: Mutate<Self::AccountId>;
         
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
         
-        /// The founder account that receives 15% of fees.
+        /// The founder account that receives 15% of fees under the default split.
         #[pallet::constant]
         type FounderAccount: Get<Self::AccountId>;
         
-        /// The DAO treasury account that receives 85% of fees.
+        /// The DAO treasury account that receives 85% of fees under the default split.
         #[pallet::constant]
         type DaoTreasuryAccount: Get<Self::AccountId>;
+
+        /// The fee engine's pallet id, used to derive the account holding collected fees.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Maps calls to the fixed fee charged by [`ChargeFixedFee`].
+        type CallToFeeType: CallToFeeType<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Origin allowed to change the fee schedule.
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of entries in a single fee schedule.
+        #[pallet::constant]
+        type MaxFeeScheduleLen: Get<u32>;
+
+        /// Minimum number of blocks between announcing a fee schedule and its activation.
+        #[pallet::constant]
+        type MinAnnouncementPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of beneficiaries sharing the collected fees.
+        #[pallet::constant]
+        type MaxFeeBeneficiaries: Get<u32>;
+
+        /// Source of the staked balances that entitle payers to fee discounts.
+        type StakeProvider: StakeProvider<Self::AccountId, BalanceOf<Self>>;
+
+        /// Maximum number of staking discount tiers.
+        #[pallet::constant]
+        type MaxDiscountTiers: Get<u32>;
+
+        /// Number of blocks in a revenue era. Must not be zero.
+        #[pallet::constant]
+        type RevenueEraLength: Get<BlockNumberFor<Self>>;
+
+        /// Number of revenue eras, including the current one, kept in [`EraRevenue`]. Must not
+        /// be zero.
+        #[pallet::constant]
+        type RevenueRetention: Get<EraIndex>;
+
+        /// Treasury the DAO's accrued fees are swept into.
+        type DaoTreasury: TreasuryDeposit<Self::AccountId, BalanceOf<Self>>;
+
+        /// Number of blocks between sweeps of the DAO's accrued fees. Must not be zero.
+        #[pallet::constant]
+        type SweepPeriod: Get<BlockNumberFor<Self>>;
     }
 
     /// Balance type for this pallet.
-    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
-
-    /// Transaction types for fee calculation.
-    pub type TransactionType = u8;
-    pub const TX_TYPE_GAS_FEE: TransactionType = 0;
-    pub const TX_TYPE_BRIDGE_SMALL: TransactionType = 1;
-    pub const TX_TYPE_BRIDGE_MEDIUM: TransactionType = 2;
-    pub const TX_TYPE_BRIDGE_LARGE: TransactionType = 3;
-    pub const TX_TYPE_NFT_BRIDGE: TransactionType = 4;
-    pub const TX_TYPE_DEX_TRADING: TransactionType = 5;
-    pub const TX_TYPE_POOL_SMALL: TransactionType = 6;
-    pub const TX_TYPE_POOL_MEDIUM: TransactionType = 7;
-    pub const TX_TYPE_POOL_LARGE: TransactionType = 8;
-    pub const TX_TYPE_POOL_OPERATIONS: TransactionType = 9;
-    pub const TX_TYPE_TOKEN_CREATION: TransactionType = 10;
-    pub const TX_TYPE_NFT_MINTING_SMALL: TransactionType = 11;
-    pub const TX_TYPE_NFT_MINTING_MEDIUM: TransactionType = 12;
-    pub const TX_TYPE_NFT_MINTING_LARGE: TransactionType = 13;
-    pub const TX_TYPE_NFT_MINTING_XLARGE: TransactionType = 14;
-    pub const TX_TYPE_VAULT_CREATION: TransactionType = 15;
-    pub const TX_TYPE_GOVERNANCE_PROPOSAL: TransactionType = 16;
+    pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// A set of fee changes, applied together.
+    pub type FeeSchedule<T> = BoundedVec<(FeeCategory, BalanceOf<T>), <T as Config>::MaxFeeScheduleLen>;
+
+    /// Ascending upper bounds of every tier but the last, one entry fewer than the tiers.
+    pub type TierThresholdsOf<T> = BoundedVec<BalanceOf<T>, ConstU32<3>>;
+
+    /// Minimum stake and fee discount of each discount tier, ascending in both.
+    pub type StakingDiscountsOf<T> = BoundedVec<(BalanceOf<T>, Permill), <T as Config>::MaxDiscountTiers>;
+
+    /// Index of a revenue era.
+    pub type EraIndex = u32;
+
+    /// Beneficiaries of collected fees and their shares, summing to 100%.
+    pub type FeeSplitOf<T> = BoundedVec<(<T as frame_system::Config>::AccountId, Permill), <T as Config>::MaxFeeBeneficiaries>;
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     /// The pallet's storage items.
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
-            if FixedFees::<T>::iter().next().is_none() {
-                Self::initialize_fees();
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            if (block_number % T::RevenueEraLength::get()).is_zero() {
+                weight = weight.saturating_add(Self::close_revenue_era());
+            }
+            if (block_number % T::SweepPeriod::get()).is_zero() {
+                weight = weight.saturating_add(Self::sweep_dao_fees());
             }
-            Weight::zero()
+            if let Some((activate_at, schedule)) = PendingFeeSchedule::<T>::get() {
+                if block_number >= activate_at {
+                    PendingFeeSchedule::<T>::kill();
+                    Self::apply_fee_schedule(&schedule);
+                    Self::deposit_event(Event::FeeScheduleActivated { block_number });
+                    weight = weight.saturating_add(
+                        T::DbWeight::get().writes(schedule.len() as u64 + 1),
+                    );
+                }
+            }
+            weight
+        }
+
+        fn integrity_test() {
+            assert!(!T::RevenueEraLength::get().is_zero(), "`RevenueEraLength` must not be zero");
+            assert!(T::RevenueRetention::get() > 0, "`RevenueRetention` must not be zero");
+            assert!(!T::SweepPeriod::get().is_zero(), "`SweepPeriod` must not be zero");
         }
     }
 
-    /// Fixed fees for each transaction type (in FI tokens).
+    /// Fixed fees for each fee category, in [`Config::Currency`]. Categories without an entry are unknown.
     #[pallet::storage]
     #[pallet::getter(fn fixed_fees)]
-    pub type FixedFees<T> = StorageMap<_, Blake2_128Concat, TransactionType, BalanceOf<T>, ValueQuery>;
+    pub type FixedFees<T> = StorageMap<_, Blake2_128Concat, FeeCategory, BalanceOf<T>, OptionQuery>;
+
+    /// Fee schedule announced to activate at the given block.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_fee_schedule)]
+    pub type PendingFeeSchedule<T: Config> = StorageValue<_, (BlockNumberFor<T>, FeeSchedule<T>), OptionQuery>;
 
     /// Total fees collected by the protocol.
     #[pallet::storage]
     #[pallet::getter(fn total_fees_collected)]
     pub type TotalFeesCollected<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-    /// Founder's fee share (15%).
+    #[pallet::type_value]
+    pub fn DefaultFeeSplit<T: Config>() -> FeeSplitOf<T> {
+        BoundedVec::truncate_from(vec![
+            (T::FounderAccount::get(), Permill::from_percent(15)),
+            (T::DaoTreasuryAccount::get(), Permill::from_percent(85)),
+        ])
+    }
+
+    /// How collected fees are split between beneficiaries.
+    #[pallet::storage]
+    #[pallet::getter(fn fee_split)]
+    pub type FeeSplit<T: Config> = StorageValue<_, FeeSplitOf<T>, ValueQuery, DefaultFeeSplit<T>>;
+
+    /// Fees accrued by each beneficiary and not yet withdrawn.
+    #[pallet::storage]
+    #[pallet::getter(fn accrued_fees)]
+    pub type AccruedFees<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Size thresholds separating the tiers of each tiered group.
+    ///
+    /// Without thresholds every operation of the group falls into its largest tier.
     #[pallet::storage]
-    #[pallet::getter(fn founder_fees)]
-    pub type FounderFees<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    #[pallet::getter(fn tier_thresholds)]
+    pub type TierThresholds<T: Config> = StorageMap<_, Blake2_128Concat, FeeTierGroup, TierThresholdsOf<T>, ValueQuery>;
 
-    /// DAO treasury fee share (85%).
+    /// Discounts on fixed fees for payers with at least the given stake.
+    ///
+    /// The tier with the highest minimum stake the payer reaches applies.
     #[pallet::storage]
-    #[pallet::getter(fn dao_fees)]
-    pub type DaoFees<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    #[pallet::getter(fn staking_discounts)]
+    pub type StakingDiscounts<T: Config> = StorageValue<_, StakingDiscountsOf<T>, ValueQuery>;
+
+    /// The revenue era fees are currently booked into.
+    #[pallet::storage]
+    #[pallet::getter(fn current_revenue_era)]
+    pub type CurrentRevenueEra<T> = StorageValue<_, EraIndex, ValueQuery>;
+
+    /// Fees collected or recorded in each retained era, by fee category.
+    #[pallet::storage]
+    #[pallet::getter(fn era_revenue)]
+    pub type EraRevenue<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Blake2_128Concat,
+        FeeCategory,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// The fee table the network launches with.
+        pub fees: Vec<(FeeCategory, BalanceOf<T>)>,
+        /// The tier thresholds the network launches with.
+        pub tier_thresholds: Vec<(FeeTierGroup, Vec<BalanceOf<T>>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (category, fee) in &self.fees {
+                assert!(!fee.is_zero(), "genesis fees must not be zero");
+                FixedFees::<T>::insert(category, fee);
+            }
+            for (group, thresholds) in &self.tier_thresholds {
+                let thresholds: TierThresholdsOf<T> = thresholds.clone().try_into()
+                    .expect("genesis tier thresholds should fit the bound");
+                Pallet::<T>::ensure_valid_thresholds(*group, &thresholds)
+                    .expect("genesis tier thresholds should be valid");
+                TierThresholds::<T>::insert(group, thresholds);
+            }
+        }
+    }
 
     /// Events that functions in this pallet can emit.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// Fee was collected and distributed.
-        /// [payer, transaction_type, amount, founder_share, dao_share]
+        /// Fee was collected and distributed according to the fee split. `amount` is what the
+        /// payer was charged after deducting its staking `discount`.
+        /// [payer, category, amount, discount]
         FeeCollected {
             payer: T::AccountId,
-            transaction_type: TransactionType,
+            category: FeeCategory,
             amount: BalanceOf<T>,
-            founder_share: BalanceOf<T>,
-            dao_share: BalanceOf<T>,
+            discount: BalanceOf<T>,
         },
         /// Fee structure was updated.
-        /// [transaction_type, new_fee]
+        /// [category, new_fee]
         FeeUpdated {
-            transaction_type: TransactionType,
+            category: FeeCategory,
             new_fee: BalanceOf<T>,
         },
-        /// Founder fees were withdrawn.
-        /// [amount]
-        FounderFeesWithdrawn {
+        /// A beneficiary withdrew its accrued fees.
+        /// [beneficiary, amount]
+        FeesWithdrawn {
+            beneficiary: T::AccountId,
             amount: BalanceOf<T>,
         },
-        /// DAO fees were withdrawn.
+        /// The fee split was changed.
+        /// [split]
+        FeeSplitUpdated {
+            split: FeeSplitOf<T>,
+        },
+        /// The tier thresholds of a group were changed.
+        /// [group, thresholds]
+        TierThresholdsUpdated {
+            group: FeeTierGroup,
+            thresholds: TierThresholdsOf<T>,
+        },
+        /// The staking discount tiers were changed.
+        /// [discounts]
+        StakingDiscountsUpdated {
+            discounts: StakingDiscountsOf<T>,
+        },
+        /// The DAO's accrued fees were swept into the DAO treasury.
         /// [amount]
-        DaoFeesWithdrawn {
+        DaoFeesSwept {
+            amount: BalanceOf<T>,
+        },
+        /// Sweeping the DAO's accrued fees failed; they remain withdrawable.
+        /// [amount, error]
+        DaoFeeSweepFailed {
+            amount: BalanceOf<T>,
+            error: DispatchError,
+        },
+        /// A revenue era ended and the next one started.
+        /// [era]
+        RevenueEraClosed {
+            era: EraIndex,
+        },
+        /// A fee schedule was announced.
+        /// [activate_at, schedule]
+        FeeScheduleAnnounced {
+            activate_at: BlockNumberFor<T>,
+            schedule: FeeSchedule<T>,
+        },
+        /// The announced fee schedule took effect.
+        /// [block_number]
+        FeeScheduleActivated {
+            block_number: BlockNumberFor<T>,
+        },
+        /// The announced fee schedule was cancelled.
+        FeeScheduleCancelled,
+        /// A fee settled outside of the fee engine was recorded.
+        /// [payer, category, amount]
+        FeeRecorded {
+            payer: T::AccountId,
+            category: FeeCategory,
             amount: BalanceOf<T>,
         },
     }
@@ -144,7 +481,7 @@ use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero, UniqueSaturatedFrom};
     pub enum Error<T> {
         /// Insufficient fee paid for the transaction.
         InsufficientFee,
-        /// Transaction type not found in fee structure.
+        /// No fee is registered for the fee category.
         UnknownTransactionType,
         /// Fee amount is zero or invalid.
         InvalidFeeAmount,
@@ -154,193 +491,449 @@ use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero, UniqueSaturatedFrom};
         Overflow,
         /// Operation would cause underflow.
         Underflow,
+        /// The fee schedule has no entries.
+        EmptyFeeSchedule,
+        /// The fee schedule activates before the minimum announcement period has passed.
+        ActivationTooSoon,
+        /// There is no announced fee schedule.
+        NoPendingFeeSchedule,
+        /// The fee split is empty, repeats a beneficiary or does not sum to 100%.
+        InvalidFeeSplit,
+        /// The tier thresholds are not strictly ascending or do not match the group's tiers.
+        InvalidTierThresholds,
+        /// The staking discount tiers are not strictly ascending in stake and discount.
+        InvalidStakingDiscounts,
     }
 
     /// The pallet's dispatchable functions.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Update the fee for a specific transaction type (only callable by founder).
+        /// Update the fee for a specific transaction type.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::update_fee())]
         pub fn update_fee(
             origin: OriginFor<T>,
-            transaction_type: TransactionType,
+            category: FeeCategory,
             new_fee: BalanceOf<T>,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-            
-            // Only founder can update fees
-            ensure!(who == T::FounderAccount::get(), Error::<T>::InsufficientFee);
+            T::UpdateOrigin::ensure_origin(origin)?;
 
             // Ensure fee is not zero
             ensure!(!new_fee.is_zero(), Error::<T>::InvalidFeeAmount);
 
             // Update the fee
-            FixedFees::<T>::insert(transaction_type.clone(), new_fee);
+            FixedFees::<T>::insert(category, new_fee);
 
             Self::deposit_event(Event::FeeUpdated {
-                transaction_type,
+                category,
                 new_fee,
             });
 
             Ok(())
         }
 
-        /// Withdraw founder's accumulated fees.
+        /// Withdraw the caller's accrued fees.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::withdraw_founder_fees())]
-        pub fn withdraw_founder_fees(origin: OriginFor<T>) -> DispatchResult {
+        #[pallet::weight(T::WeightInfo::withdraw_fees())]
+        pub fn withdraw_fees(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            
-            // Only founder can withdraw founder fees
-            ensure!(who == T::FounderAccount::get(), Error::<T>::InsufficientFee);
 
-            let founder_fees = FounderFees::<T>::get();
-            ensure!(!founder_fees.is_zero(), Error::<T>::NoFeesAvailable);
+            let amount = AccruedFees::<T>::get(&who);
+            ensure!(!amount.is_zero(), Error::<T>::NoFeesAvailable);
 
-            // Reset founder fees
-            FounderFees::<T>::put(BalanceOf::<T>::zero());
+            // Transfer fees to the beneficiary
+            T::Currency::transfer(&Self::account_id(), &who, amount, Preservation::Expendable)?;
 
-            // Transfer fees to founder
-            T::Currency::deposit_creating(&who, founder_fees);
+            AccruedFees::<T>::remove(&who);
 
-            Self::deposit_event(Event::FounderFeesWithdrawn {
-                amount: founder_fees,
+            Self::deposit_event(Event::FeesWithdrawn {
+                beneficiary: who,
+                amount,
             });
 
             Ok(())
         }
 
-        /// Withdraw DAO treasury fees.
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::withdraw_dao_fees())]
-        pub fn withdraw_dao_fees(origin: OriginFor<T>) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-            
-            // Only DAO treasury can withdraw DAO fees
-            ensure!(who == T::DaoTreasuryAccount::get(), Error::<T>::InsufficientFee);
+        /// Update the fees of several transaction types at once.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_fee_schedule(schedule.len() as u32))]
+        pub fn set_fee_schedule(origin: OriginFor<T>, schedule: FeeSchedule<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_valid_schedule(&schedule)?;
 
-            let dao_fees = DaoFees::<T>::get();
-            ensure!(!dao_fees.is_zero(), Error::<T>::NoFeesAvailable);
+            Self::apply_fee_schedule(&schedule);
 
-            // Reset DAO fees
-            DaoFees::<T>::put(BalanceOf::<T>::zero());
+            Ok(())
+        }
+
+        /// Announce a fee schedule that takes effect at `activate_at`.
+        ///
+        /// Replaces any previously announced schedule.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::announce_fee_schedule(schedule.len() as u32))]
+        pub fn announce_fee_schedule(
+            origin: OriginFor<T>,
+            schedule: FeeSchedule<T>,
+            activate_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_valid_schedule(&schedule)?;
+
+            let earliest = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::MinAnnouncementPeriod::get());
+            ensure!(activate_at >= earliest, Error::<T>::ActivationTooSoon);
 
-            // Transfer fees to DAO treasury
-            T::Currency::deposit_creating(&who, dao_fees);
+            PendingFeeSchedule::<T>::put((activate_at, schedule.clone()));
 
-            Self::deposit_event(Event::DaoFeesWithdrawn {
-                amount: dao_fees,
+            Self::deposit_event(Event::FeeScheduleAnnounced {
+                activate_at,
+                schedule,
             });
 
             Ok(())
         }
+
+        /// Replace the split of collected fees between beneficiaries.
+        ///
+        /// Fees accrued under the previous split remain withdrawable.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_fee_split())]
+        pub fn set_fee_split(origin: OriginFor<T>, split: FeeSplitOf<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            ensure!(!split.is_empty(), Error::<T>::InvalidFeeSplit);
+            let total: u32 = split.iter().map(|(_, share)| share.deconstruct()).sum();
+            ensure!(total == Permill::one().deconstruct(), Error::<T>::InvalidFeeSplit);
+            let mut beneficiaries: Vec<_> = split.iter().map(|(who, _)| who).collect();
+            beneficiaries.sort();
+            beneficiaries.dedup();
+            ensure!(beneficiaries.len() == split.len(), Error::<T>::InvalidFeeSplit);
+
+            FeeSplit::<T>::put(split.clone());
+
+            Self::deposit_event(Event::FeeSplitUpdated { split });
+
+            Ok(())
+        }
+
+        /// Cancel the announced fee schedule.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::cancel_fee_schedule())]
+        pub fn cancel_fee_schedule(origin: OriginFor<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(PendingFeeSchedule::<T>::exists(), Error::<T>::NoPendingFeeSchedule);
+
+            PendingFeeSchedule::<T>::kill();
+
+            Self::deposit_event(Event::FeeScheduleCancelled);
+
+            Ok(())
+        }
+
+        /// Set the size thresholds separating the tiers of `group`.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_tier_thresholds())]
+        pub fn set_tier_thresholds(
+            origin: OriginFor<T>,
+            group: FeeTierGroup,
+            thresholds: TierThresholdsOf<T>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_valid_thresholds(group, &thresholds)?;
+
+            TierThresholds::<T>::insert(group, thresholds.clone());
+
+            Self::deposit_event(Event::TierThresholdsUpdated { group, thresholds });
+
+            Ok(())
+        }
+
+        /// Replace the staking discount tiers. An empty list disables discounts.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::set_staking_discounts())]
+        pub fn set_staking_discounts(origin: OriginFor<T>, discounts: StakingDiscountsOf<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                discounts.windows(2).all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1),
+                Error::<T>::InvalidStakingDiscounts
+            );
+
+            StakingDiscounts::<T>::put(discounts.clone());
+
+            Self::deposit_event(Event::StakingDiscountsUpdated { discounts });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
-        /// Get the fixed fee for a transaction type.
-        pub fn get_fee(transaction_type: &TransactionType) -> BalanceOf<T> {
-            FixedFees::<T>::get(transaction_type)
+        /// The account holding collected fees until they are withdrawn.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Get the fixed fee for a fee category.
+        pub fn get_fee(category: &FeeCategory) -> Result<BalanceOf<T>, DispatchError> {
+            FixedFees::<T>::get(category).ok_or_else(|| Error::<T>::UnknownTransactionType.into())
+        }
+
+        /// Check if the provided fee is sufficient for the fee category.
+        pub fn check_fee(category: &FeeCategory, fee_paid: BalanceOf<T>) -> bool {
+            Self::get_fee(category).is_ok_and(|required_fee| fee_paid >= required_fee)
         }
 
-        /// Check if the provided fee is sufficient for the transaction type.
-        pub fn check_fee(transaction_type: &TransactionType, fee_paid: BalanceOf<T>) -> bool {
-            let required_fee = Self::get_fee(transaction_type);
-            fee_paid >= required_fee
+        /// Every fee category with a registered fee, and its fixed fee.
+        pub fn fee_schedule() -> Vec<(FeeCategory, BalanceOf<T>)> {
+            let mut schedule: Vec<_> = FixedFees::<T>::iter().collect();
+            schedule.sort_by_key(|(category, _)| *category);
+            schedule
+        }
+
+        /// The fixed fee `who` pays for `call` after its staking discount.
+        ///
+        /// `None` if the call only pays gas or its category has no registered fee.
+        pub fn call_fee(
+            who: &T::AccountId,
+            call: &<T as frame_system::Config>::RuntimeCall,
+        ) -> Option<(FeeCategory, BalanceOf<T>)> {
+            let category = T::CallToFeeType::fee_type(call)?;
+            let fee = FixedFees::<T>::get(category)?;
+            Some((category, fee.saturating_sub(Self::staking_discount(who, fee))))
+        }
+
+        /// The discount `payer` gets on a fixed fee of `fee_amount` for its stake.
+        pub fn staking_discount(payer: &T::AccountId, fee_amount: BalanceOf<T>) -> BalanceOf<T> {
+            let staked = T::StakeProvider::staked_balance(payer);
+            StakingDiscounts::<T>::get()
+                .iter()
+                .rev()
+                .find(|(min_stake, _)| staked >= *min_stake)
+                .map_or_else(Zero::zero, |(_, discount)| discount.mul_floor(fee_amount))
         }
 
         /// Collect and distribute fees for a transaction.
         /// This is called internally by other pallets when processing transactions.
+        ///
+        /// `fee_amount` is the undiscounted fixed fee; the payer's staking discount is deducted
+        /// before it is charged.
         pub fn collect_fee(
             payer: &T::AccountId,
-            transaction_type: TransactionType,
+            category: FeeCategory,
             fee_amount: BalanceOf<T>,
         ) -> DispatchResult {
-            // Calculate fee distribution (15% founder, 85% DAO)
-            let founder_share = fee_amount
-                .checked_mul(&15u32.into())
-                .ok_or(Error::<T>::Overflow)?
-                .checked_div(&100u32.into())
-                .ok_or(Error::<T>::Underflow)?;
-            
-            let dao_share = fee_amount
-                .checked_sub(&founder_share)
-                .ok_or(Error::<T>::Underflow)?;
+            let discount = Self::staking_discount(payer, fee_amount);
+            let fee_amount = fee_amount.saturating_sub(discount);
+
+            // Move the fee into the pallet account
+            T::Currency::transfer(payer, &Self::account_id(), fee_amount, Preservation::Preserve)?;
+
+            Self::distribute_fee(category, fee_amount)?;
+
+            Self::deposit_event(Event::FeeCollected {
+                payer: payer.clone(),
+                category,
+                amount: fee_amount,
+                discount,
+            });
+
+            Ok(())
+        }
+
+        /// Distribute a fee that `payer` already paid into the pallet account.
+        ///
+        /// Used for fees that are taken before the final amount is known, such as gas, which is
+        /// withdrawn before dispatch and partly refunded afterwards. No staking discount applies.
+        pub fn credit_fee(
+            payer: &T::AccountId,
+            category: FeeCategory,
+            fee_amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            Self::distribute_fee(category, fee_amount)?;
+
+            Self::deposit_event(Event::FeeCollected {
+                payer: payer.clone(),
+                category,
+                amount: fee_amount,
+                discount: Zero::zero(),
+            });
+
+            Ok(())
+        }
 
+        /// Book a fee held by the pallet account and split it between the beneficiaries.
+        fn distribute_fee(category: FeeCategory, fee_amount: BalanceOf<T>) -> DispatchResult {
             // Update fee tracking
             let total_fees = TotalFeesCollected::<T>::get()
                 .checked_add(&fee_amount)
                 .ok_or(Error::<T>::Overflow)?;
             TotalFeesCollected::<T>::put(total_fees);
+            Self::note_revenue(category, fee_amount);
+
+            // Distribute the fee, the last beneficiary receives the rounding remainder
+            let split = FeeSplit::<T>::get();
+            let mut remaining = fee_amount;
+            for (index, (beneficiary, share)) in split.iter().enumerate() {
+                let amount = if index + 1 == split.len() {
+                    remaining
+                } else {
+                    share.mul_floor(fee_amount).min(remaining)
+                };
+                remaining = remaining.saturating_sub(amount);
+                AccruedFees::<T>::mutate(beneficiary, |accrued| *accrued = accrued.saturating_add(amount));
+            }
 
-            let founder_fees = FounderFees::<T>::get()
-                .checked_add(&founder_share)
-                .ok_or(Error::<T>::Overflow)?;
-            FounderFees::<T>::put(founder_fees);
+            Ok(())
+        }
 
-            let dao_fees = DaoFees::<T>::get()
-                .checked_add(&dao_share)
-                .ok_or(Error::<T>::Overflow)?;
-            DaoFees::<T>::put(dao_fees);
+        /// The tier of `group` that applies to an operation of size `value`.
+        ///
+        /// A value below the first threshold is in the smallest tier, a value at or above the last
+        /// threshold in the largest.
+        pub fn tier_category(group: FeeTierGroup, value: BalanceOf<T>) -> FeeCategory {
+            let tiers = group.tiers();
+            let thresholds = TierThresholds::<T>::get(group);
+            if thresholds.is_empty() {
+                return tiers[tiers.len() - 1];
+            }
+            let tier = thresholds.iter().take_while(|threshold| value >= **threshold).count();
+            tiers[tier]
+        }
 
-            Self::deposit_event(Event::FeeCollected {
-                payer: payer.clone(),
-                transaction_type,
-                amount: fee_amount,
-                founder_share,
-                dao_share,
-            });
+        /// The tier of `group` that applies to an operation of size `value`, and its fixed fee.
+        pub fn resolve_fee(
+            group: FeeTierGroup,
+            value: BalanceOf<T>,
+        ) -> Result<(FeeCategory, BalanceOf<T>), DispatchError> {
+            let category = Self::tier_category(group, value);
+            Ok((category, Self::get_fee(&category)?))
+        }
 
+        /// Ensure tier thresholds are strictly ascending with one entry per tier boundary.
+        fn ensure_valid_thresholds(group: FeeTierGroup, thresholds: &TierThresholdsOf<T>) -> DispatchResult {
+            ensure!(thresholds.len() + 1 == group.tiers().len(), Error::<T>::InvalidTierThresholds);
+            ensure!(thresholds.windows(2).all(|pair| pair[0] < pair[1]), Error::<T>::InvalidTierThresholds);
             Ok(())
         }
 
-        /// Initialize the default fee structure.
-        /// This should be called during genesis.
-        pub fn initialize_fees() {
-            // Convert FI token amounts to BalanceOf<T> (assuming 12 decimal places)
-            let fi_unit: u128 = 1_000_000_000_000; // 1 FI = 10^12 smallest units
-            
-            // Gas Fee: 0.01 FI
-            FixedFees::<T>::insert(TX_TYPE_GAS_FEE, BalanceOf::<T>::unique_saturated_from(fi_unit / 100));
-            
-            // Bridge fees
-            FixedFees::<T>::insert(TX_TYPE_BRIDGE_SMALL, BalanceOf::<T>::unique_saturated_from(fi_unit / 20));      // 0.05 FI
-            FixedFees::<T>::insert(TX_TYPE_BRIDGE_MEDIUM, BalanceOf::<T>::unique_saturated_from(fi_unit / 10));     // 0.1 FI
-            FixedFees::<T>::insert(TX_TYPE_BRIDGE_LARGE, BalanceOf::<T>::unique_saturated_from(fi_unit / 2));       // 0.5 FI
-            FixedFees::<T>::insert(TX_TYPE_NFT_BRIDGE, BalanceOf::<T>::unique_saturated_from(fi_unit / 20));        // 0.05 FI
-            
-            // DEX fees
-            FixedFees::<T>::insert(TX_TYPE_DEX_TRADING, BalanceOf::<T>::unique_saturated_from(fi_unit / 100));      // 0.01 FI
-            FixedFees::<T>::insert(TX_TYPE_POOL_SMALL, BalanceOf::<T>::unique_saturated_from(fi_unit));             // 1.0 FI
-            FixedFees::<T>::insert(TX_TYPE_POOL_MEDIUM, BalanceOf::<T>::unique_saturated_from(fi_unit * 2));        // 2.0 FI
-            FixedFees::<T>::insert(TX_TYPE_POOL_LARGE, BalanceOf::<T>::unique_saturated_from(fi_unit * 5));         // 5.0 FI
-            FixedFees::<T>::insert(TX_TYPE_POOL_OPERATIONS, BalanceOf::<T>::unique_saturated_from(fi_unit / 100));  // 0.01 FI
-            
-            // Token and NFT fees
-            FixedFees::<T>::insert(TX_TYPE_TOKEN_CREATION, BalanceOf::<T>::unique_saturated_from(fi_unit / 2));     // 0.5 FI
-            FixedFees::<T>::insert(TX_TYPE_NFT_MINTING_SMALL, BalanceOf::<T>::unique_saturated_from(fi_unit / 20)); // 0.05 FI
-            FixedFees::<T>::insert(TX_TYPE_NFT_MINTING_MEDIUM, BalanceOf::<T>::unique_saturated_from(fi_unit / 5)); // 0.2 FI
-            FixedFees::<T>::insert(TX_TYPE_NFT_MINTING_LARGE, BalanceOf::<T>::unique_saturated_from(fi_unit));      // 1.0 FI
-            FixedFees::<T>::insert(TX_TYPE_NFT_MINTING_XLARGE, BalanceOf::<T>::unique_saturated_from(fi_unit * 2)); // 2.0 FI
-            
-            // Other fees
-            FixedFees::<T>::insert(TX_TYPE_VAULT_CREATION, BalanceOf::<T>::unique_saturated_from(fi_unit / 20));    // 0.05 FI
-            FixedFees::<T>::insert(TX_TYPE_GOVERNANCE_PROPOSAL, BalanceOf::<T>::unique_saturated_from(fi_unit / 2)); // 0.5 FI
+        /// Ensure a fee schedule is non-empty and has no zero fees.
+        fn ensure_valid_schedule(schedule: &FeeSchedule<T>) -> DispatchResult {
+            ensure!(!schedule.is_empty(), Error::<T>::EmptyFeeSchedule);
+            ensure!(schedule.iter().all(|(_, fee)| !fee.is_zero()), Error::<T>::InvalidFeeAmount);
+            Ok(())
+        }
+
+        /// Write every entry of a fee schedule to `FixedFees`.
+        fn apply_fee_schedule(schedule: &FeeSchedule<T>) {
+            for (category, new_fee) in schedule.iter().copied() {
+                FixedFees::<T>::insert(category, new_fee);
+
+                Self::deposit_event(Event::FeeUpdated {
+                    category,
+                    new_fee,
+                });
+            }
+        }
+
+        /// Move the fees accrued by the DAO treasury account into [`Config::DaoTreasury`].
+        fn sweep_dao_fees() -> Weight {
+            let dao = T::DaoTreasuryAccount::get();
+            let amount = AccruedFees::<T>::get(&dao);
+            if amount.is_zero() {
+                return T::DbWeight::get().reads(1);
+            }
+
+            match T::DaoTreasury::deposit(&Self::account_id(), amount) {
+                Ok(()) => {
+                    AccruedFees::<T>::remove(&dao);
+                    Self::deposit_event(Event::DaoFeesSwept { amount });
+                },
+                Err(error) => Self::deposit_event(Event::DaoFeeSweepFailed { amount, error }),
+            }
+
+            // The accrued fees plus the treasury deposit
+            T::DbWeight::get().reads_writes(4, 4)
+        }
+
+        /// Book a fee into the revenue of the current era.
+        fn note_revenue(category: FeeCategory, amount: BalanceOf<T>) {
+            EraRevenue::<T>::mutate(CurrentRevenueEra::<T>::get(), category, |revenue| {
+                *revenue = revenue.saturating_add(amount)
+            });
+        }
+
+        /// Start the next revenue era and prune the era that falls out of retention.
+        fn close_revenue_era() -> Weight {
+            let era = CurrentRevenueEra::<T>::get();
+            let next = era.saturating_add(1);
+            CurrentRevenueEra::<T>::put(next);
+
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            if let Some(expired) = next.checked_sub(T::RevenueRetention::get()) {
+                // At most one entry per fee category
+                let removed = EraRevenue::<T>::clear_prefix(expired, u32::MAX, None);
+                weight = weight.saturating_add(T::DbWeight::get().writes(removed.unique as u64));
+            }
+
+            Self::deposit_event(Event::RevenueEraClosed { era });
+
+            weight
+        }
+
+        /// Revenue of `era` by fee category, empty if the era is unknown or pruned.
+        pub fn era_revenue_by_category(era: EraIndex) -> Vec<(FeeCategory, BalanceOf<T>)> {
+            let mut revenue: Vec<_> = EraRevenue::<T>::iter_prefix(era).collect();
+            revenue.sort_by_key(|(category, _)| *category);
+            revenue
+        }
+
+        /// Record a fee that was paid outside of `T::Currency`.
+        ///
+        /// Only the protocol total is updated; there is nothing for the beneficiaries to
+        /// withdraw.
+        pub fn record_fee(
+            payer: &T::AccountId,
+            category: FeeCategory,
+            fee_amount: BalanceOf<T>,
+        ) {
+            TotalFeesCollected::<T>::mutate(|total| *total = total.saturating_add(fee_amount));
+            Self::note_revenue(category, fee_amount);
+
+            Self::deposit_event(Event::FeeRecorded {
+                payer: payer.clone(),
+                category,
+                amount: fee_amount,
+            });
         }
     }
 
     impl<T: Config> FeeEngineInterface<T::AccountId, BalanceOf<T>> for Pallet<T> {
-        fn collect_fee(payer: &T::AccountId, transaction_type: u8, fee_amount: BalanceOf<T>) -> Result<(), &'static str> {
-            Self::collect_fee(payer, transaction_type, fee_amount).map_err(|_| "Fee collection failed")
+        fn collect_fee(payer: &T::AccountId, category: FeeCategory, fee_amount: BalanceOf<T>) -> Result<(), &'static str> {
+            Self::collect_fee(payer, category, fee_amount).map_err(|_| "Fee collection failed")
+        }
+
+        fn record_fee(payer: &T::AccountId, category: FeeCategory, fee_amount: BalanceOf<T>) {
+            Self::record_fee(payer, category, fee_amount)
+        }
+
+        fn credit_fee(payer: &T::AccountId, category: FeeCategory, fee_amount: BalanceOf<T>) -> Result<(), &'static str> {
+            Self::credit_fee(payer, category, fee_amount).map_err(|_| "Fee credit failed")
+        }
+
+        fn account_id() -> T::AccountId {
+            Self::account_id()
+        }
+
+        fn get_fee(category: &FeeCategory) -> Result<BalanceOf<T>, &'static str> {
+            Self::get_fee(category).map_err(|_| "Unknown fee category")
         }
 
-        fn get_fee(transaction_type: &u8) -> BalanceOf<T> {
-            FixedFees::<T>::get(transaction_type)
+        fn check_fee(category: &FeeCategory, fee_paid: BalanceOf<T>) -> bool {
+            Self::check_fee(category, fee_paid)
         }
 
-        fn check_fee(transaction_type: &u8, fee_paid: BalanceOf<T>) -> bool {
-            let required_fee = FixedFees::<T>::get(transaction_type);
-            fee_paid >= required_fee
+        fn resolve_fee(group: FeeTierGroup, value: BalanceOf<T>) -> Result<(FeeCategory, BalanceOf<T>), &'static str> {
+            Self::resolve_fee(group, value).map_err(|_| "Unknown fee category")
         }
     }
 }
\ No newline at end of file
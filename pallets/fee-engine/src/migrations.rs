@@ -0,0 +1,125 @@
+//! Storage migrations for the fee engine pallet.
+
+/// Migrate `FixedFees` from raw `u8` keys to [`FeeCategory`](crate::FeeCategory) keys.
+pub mod v1 {
+    use crate::{BalanceOf, Config, FeeCategory, Pallet};
+    use codec::Decode;
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_std::vec::Vec;
+
+    /// `FixedFees` as it was stored in version 0.
+    #[frame_support::storage_alias]
+    pub type FixedFees<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u8, BalanceOf<T>, OptionQuery>;
+
+    /// Drops the fees of `u8` identifiers that have no [`FeeCategory`].
+    ///
+    /// Known identifiers need no rewrite: `FeeCategory` encodes each variant as its old `u8`.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let keys: Vec<u8> = FixedFees::<T>::iter_keys().collect();
+            let mut removed = 0u64;
+            for key in &keys {
+                if FeeCategory::decode(&mut &[*key][..]).is_err() {
+                    FixedFees::<T>::remove(key);
+                    removed += 1;
+                }
+            }
+            T::DbWeight::get().reads_writes(keys.len() as u64, removed)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                FixedFees::<T>::iter_keys().all(|key| FeeCategory::decode(&mut &[key][..]).is_ok()),
+                "unknown fee category left in FixedFees"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`InnerMigrateV0ToV1`] guarded by the pallet's storage version.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Move the founder and DAO fee totals into the per-beneficiary `AccruedFees` map.
+pub mod v2 {
+    use crate::{AccruedFees, BalanceOf, Config, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_runtime::traits::{Saturating, Zero};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Fees accrued by [`Config::FounderAccount`] in version 1.
+    #[frame_support::storage_alias]
+    pub type FounderFees<T: Config> = StorageValue<Pallet<T>, BalanceOf<T>, ValueQuery>;
+
+    /// Fees accrued by [`Config::DaoTreasuryAccount`] in version 1.
+    #[frame_support::storage_alias]
+    pub type DaoFees<T: Config> = StorageValue<Pallet<T>, BalanceOf<T>, ValueQuery>;
+
+    /// Credits the old totals to the founder and DAO treasury accounts and kills the old keys.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            for (account, fees) in [
+                (T::FounderAccount::get(), FounderFees::<T>::take()),
+                (T::DaoTreasuryAccount::get(), DaoFees::<T>::take()),
+            ] {
+                if !fees.is_zero() {
+                    AccruedFees::<T>::mutate(account, |accrued| *accrued = accrued.saturating_add(fees));
+                }
+            }
+            T::DbWeight::get().reads_writes(4, 4)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let founder = AccruedFees::<T>::get(T::FounderAccount::get())
+                .saturating_add(FounderFees::<T>::get());
+            let dao = AccruedFees::<T>::get(T::DaoTreasuryAccount::get())
+                .saturating_add(DaoFees::<T>::get());
+            Ok((founder, dao).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let (founder, dao) = <(BalanceOf<T>, BalanceOf<T>)>::decode(&mut &state[..])
+                .map_err(|_| "invalid pre-upgrade state")?;
+            ensure!(!FounderFees::<T>::exists() && !DaoFees::<T>::exists(), "old fee totals left");
+            ensure!(
+                AccruedFees::<T>::get(T::FounderAccount::get()) == founder
+                    && AccruedFees::<T>::get(T::DaoTreasuryAccount::get()) == dao,
+                "fee totals not moved into AccruedFees"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`InnerMigrateV1ToV2`] guarded by the pallet's storage version.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
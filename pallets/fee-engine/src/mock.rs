@@ -0,0 +1,138 @@
+use crate::{self as pallet_fee_engine, CallToFeeType, FeeCategory, StakeProvider, TreasuryDeposit};
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Currency, ExistenceRequirement},
+    PalletId,
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{BuildStorage, DispatchResult};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type FeeEngine = pallet_fee_engine::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const FeeEnginePalletId: PalletId = PalletId(*b"cfi/fees");
+    pub static Stakes: Vec<(u64, u64)> = vec![];
+}
+
+pub struct MockTreasury;
+
+impl TreasuryDeposit<u64, u64> for MockTreasury {
+    fn deposit(source: &u64, amount: u64) -> DispatchResult {
+        Balances::transfer(source, &TREASURY, amount, ExistenceRequirement::AllowDeath)
+    }
+}
+
+pub struct MockCallToFeeType;
+
+// Remarks with an event pay the DEX trading fee, heap page changes the pool operations fee, which
+// is not registered at genesis. Every other call only pays gas.
+impl CallToFeeType<RuntimeCall> for MockCallToFeeType {
+    fn fee_type(call: &RuntimeCall) -> Option<FeeCategory> {
+        match call {
+            RuntimeCall::System(frame_system::Call::remark_with_event { .. }) => Some(FeeCategory::DexTrading),
+            RuntimeCall::System(frame_system::Call::set_heap_pages { .. }) => Some(FeeCategory::PoolOperations),
+            _ => None,
+        }
+    }
+}
+
+pub struct MockStakes;
+
+impl StakeProvider<u64, u64> for MockStakes {
+    fn staked_balance(who: &u64) -> u64 {
+        Stakes::get().iter().find(|(staker, _)| staker == who).map_or(0, |(_, staked)| *staked)
+    }
+}
+
+impl pallet_fee_engine::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type WeightInfo = ();
+    type FounderAccount = ConstU64<FOUNDER>;
+    type DaoTreasuryAccount = ConstU64<DAO>;
+    type CallToFeeType = MockCallToFeeType;
+    type PalletId = FeeEnginePalletId;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type MaxFeeScheduleLen = ConstU32<4>;
+    type MinAnnouncementPeriod = ConstU64<ANNOUNCEMENT_PERIOD>;
+    type MaxFeeBeneficiaries = ConstU32<4>;
+    type StakeProvider = MockStakes;
+    type MaxDiscountTiers = ConstU32<3>;
+    type RevenueEraLength = ConstU64<ERA_LENGTH>;
+    type RevenueRetention = ConstU32<2>;
+    type DaoTreasury = MockTreasury;
+    type SweepPeriod = ConstU64<SWEEP_PERIOD>;
+}
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const FOUNDER: u64 = 100;
+pub const DAO: u64 = 101;
+pub const TREASURY: u64 = 102;
+pub const ANNOUNCEMENT_PERIOD: u64 = 10;
+pub const ERA_LENGTH: u64 = 5;
+pub const SWEEP_PERIOD: u64 = 7;
+
+pub const GAS_FEE: u64 = 3;
+
+// Build genesis storage with `ALICE` endowed, `BOB` holding nothing and only the gas fee set.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> { balances: vec![(ALICE, 10_000)], ..Default::default() }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+    pallet_fee_engine::GenesisConfig::<Test> { fees: vec![(FeeCategory::GasFee, GAS_FEE)], ..Default::default() }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// The fee engine account holds exactly the fees that are still owed to beneficiaries.
+pub fn assert_fee_account_backs_owed_fees() {
+    assert_eq!(
+        Balances::free_balance(FeeEngine::account_id()),
+        pallet_fee_engine::AccruedFees::<Test>::iter_values().sum::<u64>()
+    );
+}
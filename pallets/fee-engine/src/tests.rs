@@ -0,0 +1,611 @@
+use crate::{
+    mock::*, ChargeFixedFee, Error, Event, FeeCategory, FeeSchedule, FeeSplitOf, FeeTierGroup,
+    StakingDiscountsOf, TierThresholdsOf, WeightInfo,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::DispatchInfo,
+    traits::Hooks,
+    weights::Weight,
+};
+use sp_runtime::{
+    traits::{DispatchTransaction, TransactionExtension},
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+    DispatchError, Permill,
+};
+
+#[test]
+fn collect_fee_moves_funds_into_pallet_account() {
+    new_test_ext().execute_with(|| {
+        let issuance = Balances::total_issuance();
+
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+
+        assert_eq!(Balances::free_balance(ALICE), 9_000);
+        assert_eq!(Balances::free_balance(FeeEngine::account_id()), 1_000);
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER), 150);
+        assert_eq!(FeeEngine::accrued_fees(DAO), 850);
+        assert_eq!(FeeEngine::total_fees_collected(), 1_000);
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_fee_account_backs_owed_fees();
+    });
+}
+
+#[test]
+fn collect_fee_fails_when_payer_cannot_pay() {
+    new_test_ext().execute_with(|| {
+        assert!(FeeEngine::collect_fee(&BOB, FeeCategory::DexTrading, 1_000).is_err());
+        assert!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 10_000).is_err());
+
+        assert_eq!(FeeEngine::total_fees_collected(), 0);
+        assert_eq!(Balances::free_balance(FeeEngine::account_id()), 0);
+    });
+}
+
+#[test]
+fn withdrawals_transfer_out_without_minting() {
+    new_test_ext().execute_with(|| {
+        let issuance = Balances::total_issuance();
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+
+        assert_ok!(FeeEngine::withdraw_fees(RuntimeOrigin::signed(FOUNDER)));
+        System::assert_last_event(Event::FeesWithdrawn { beneficiary: FOUNDER, amount: 150 }.into());
+        assert_eq!(Balances::free_balance(FOUNDER), 150);
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER), 0);
+        assert_fee_account_backs_owed_fees();
+
+        assert_ok!(FeeEngine::withdraw_fees(RuntimeOrigin::signed(DAO)));
+        System::assert_last_event(Event::FeesWithdrawn { beneficiary: DAO, amount: 850 }.into());
+        assert_eq!(Balances::free_balance(DAO), 850);
+        assert_eq!(FeeEngine::accrued_fees(DAO), 0);
+        assert_fee_account_backs_owed_fees();
+
+        assert_eq!(Balances::free_balance(FeeEngine::account_id()), 0);
+        assert_eq!(Balances::total_issuance(), issuance);
+    });
+}
+
+#[test]
+fn only_beneficiaries_can_withdraw() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeEngine::withdraw_fees(RuntimeOrigin::signed(FOUNDER)),
+            Error::<Test>::NoFeesAvailable
+        );
+
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+        assert_noop!(
+            FeeEngine::withdraw_fees(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::NoFeesAvailable
+        );
+    });
+}
+
+#[test]
+fn record_fee_only_updates_the_total() {
+    new_test_ext().execute_with(|| {
+        let issuance = Balances::total_issuance();
+
+        FeeEngine::record_fee(&ALICE, FeeCategory::GasFee, 500);
+
+        System::assert_last_event(
+            Event::FeeRecorded { payer: ALICE, category: FeeCategory::GasFee, amount: 500 }.into(),
+        );
+        assert_eq!(FeeEngine::total_fees_collected(), 500);
+        assert_eq!(Balances::free_balance(ALICE), 10_000);
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_fee_account_backs_owed_fees();
+    });
+}
+
+#[test]
+fn credited_fees_are_split_without_another_transfer() {
+    new_test_ext().execute_with(|| {
+        // The payer already moved the fee into the pallet account
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(ALICE), FeeEngine::account_id(), 1_000));
+        assert_ok!(FeeEngine::set_staking_discounts(RuntimeOrigin::root(), discounts(&[(100, 10)])));
+        Stakes::set(vec![(ALICE, 100)]);
+
+        assert_ok!(FeeEngine::credit_fee(&ALICE, FeeCategory::GasFee, 1_000));
+
+        System::assert_last_event(
+            Event::FeeCollected { payer: ALICE, category: FeeCategory::GasFee, amount: 1_000, discount: 0 }.into(),
+        );
+        assert_eq!(Balances::free_balance(ALICE), 9_000);
+        assert_eq!(FeeEngine::total_fees_collected(), 1_000);
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER), 150);
+        assert_eq!(FeeEngine::accrued_fees(DAO), 850);
+        assert_fee_account_backs_owed_fees();
+    });
+}
+
+fn schedule(entries: &[(FeeCategory, u64)]) -> FeeSchedule<Test> {
+    entries.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn update_fee_requires_update_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeEngine::update_fee(RuntimeOrigin::signed(FOUNDER), FeeCategory::DexTrading, 5),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(FeeEngine::update_fee(RuntimeOrigin::root(), FeeCategory::DexTrading, 5));
+        assert_eq!(FeeEngine::fixed_fees(FeeCategory::DexTrading), Some(5));
+    });
+}
+
+#[test]
+fn set_fee_schedule_applies_all_entries() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeEngine::set_fee_schedule(RuntimeOrigin::root(), schedule(&[])),
+            Error::<Test>::EmptyFeeSchedule
+        );
+        assert_noop!(
+            FeeEngine::set_fee_schedule(RuntimeOrigin::root(), schedule(&[(FeeCategory::GasFee, 0)])),
+            Error::<Test>::InvalidFeeAmount
+        );
+
+        assert_ok!(FeeEngine::set_fee_schedule(
+            RuntimeOrigin::root(),
+            schedule(&[(FeeCategory::GasFee, 2), (FeeCategory::DexTrading, 7)])
+        ));
+        assert_eq!(FeeEngine::fixed_fees(FeeCategory::GasFee), Some(2));
+        assert_eq!(FeeEngine::fixed_fees(FeeCategory::DexTrading), Some(7));
+        System::assert_last_event(Event::FeeUpdated { category: FeeCategory::DexTrading, new_fee: 7 }.into());
+    });
+}
+
+#[test]
+fn announced_fee_schedule_activates_at_its_block() {
+    new_test_ext().execute_with(|| {
+        let activate_at = 1 + ANNOUNCEMENT_PERIOD;
+        assert_noop!(
+            FeeEngine::announce_fee_schedule(
+                RuntimeOrigin::root(),
+                schedule(&[(FeeCategory::DexTrading, 7)]),
+                activate_at - 1
+            ),
+            Error::<Test>::ActivationTooSoon
+        );
+
+        assert_ok!(FeeEngine::announce_fee_schedule(
+            RuntimeOrigin::root(),
+            schedule(&[(FeeCategory::DexTrading, 7)]),
+            activate_at
+        ));
+        System::assert_last_event(
+            Event::FeeScheduleAnnounced { activate_at, schedule: schedule(&[(FeeCategory::DexTrading, 7)]) }.into(),
+        );
+
+        FeeEngine::on_initialize(activate_at - 1);
+        assert_ne!(FeeEngine::fixed_fees(FeeCategory::DexTrading), Some(7));
+        assert!(FeeEngine::pending_fee_schedule().is_some());
+
+        FeeEngine::on_initialize(activate_at);
+        assert_eq!(FeeEngine::fixed_fees(FeeCategory::DexTrading), Some(7));
+        assert!(FeeEngine::pending_fee_schedule().is_none());
+        System::assert_last_event(Event::FeeScheduleActivated { block_number: activate_at }.into());
+    });
+}
+
+#[test]
+fn announced_fee_schedule_can_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeEngine::cancel_fee_schedule(RuntimeOrigin::root()),
+            Error::<Test>::NoPendingFeeSchedule
+        );
+
+        let activate_at = 1 + ANNOUNCEMENT_PERIOD;
+        assert_ok!(FeeEngine::announce_fee_schedule(
+            RuntimeOrigin::root(),
+            schedule(&[(FeeCategory::DexTrading, 7)]),
+            activate_at
+        ));
+        assert_noop!(FeeEngine::cancel_fee_schedule(RuntimeOrigin::signed(FOUNDER)), DispatchError::BadOrigin);
+        assert_ok!(FeeEngine::cancel_fee_schedule(RuntimeOrigin::root()));
+        System::assert_last_event(Event::FeeScheduleCancelled.into());
+
+        FeeEngine::on_initialize(activate_at);
+        assert_ne!(FeeEngine::fixed_fees(FeeCategory::DexTrading), Some(7));
+    });
+}
+
+fn split(entries: &[(u64, u32)]) -> FeeSplitOf<Test> {
+    entries
+        .iter()
+        .map(|(who, percent)| (*who, Permill::from_percent(*percent)))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn set_fee_split_validates_shares() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeEngine::set_fee_split(RuntimeOrigin::signed(FOUNDER), split(&[(BOB, 100)])),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(FeeEngine::set_fee_split(RuntimeOrigin::root(), split(&[])), Error::<Test>::InvalidFeeSplit);
+        assert_noop!(
+            FeeEngine::set_fee_split(RuntimeOrigin::root(), split(&[(FOUNDER, 50), (DAO, 40)])),
+            Error::<Test>::InvalidFeeSplit
+        );
+        assert_noop!(
+            FeeEngine::set_fee_split(RuntimeOrigin::root(), split(&[(DAO, 50), (DAO, 50)])),
+            Error::<Test>::InvalidFeeSplit
+        );
+
+        assert_ok!(FeeEngine::set_fee_split(RuntimeOrigin::root(), split(&[(FOUNDER, 10), (DAO, 90)])));
+        System::assert_last_event(Event::FeeSplitUpdated { split: split(&[(FOUNDER, 10), (DAO, 90)]) }.into());
+    });
+}
+
+#[test]
+fn fees_follow_the_configured_split() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FeeEngine::set_fee_split(
+            RuntimeOrigin::root(),
+            split(&[(FOUNDER, 10), (BOB, 30), (DAO, 60)])
+        ));
+
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_001));
+
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER), 100);
+        assert_eq!(FeeEngine::accrued_fees(BOB), 300);
+        // The last beneficiary receives the rounding remainder
+        assert_eq!(FeeEngine::accrued_fees(DAO), 601);
+        assert_fee_account_backs_owed_fees();
+
+        assert_ok!(FeeEngine::withdraw_fees(RuntimeOrigin::signed(BOB)));
+        assert_eq!(Balances::free_balance(BOB), 300);
+        assert_fee_account_backs_owed_fees();
+    });
+}
+
+#[test]
+fn get_fee_rejects_unknown_categories() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(FeeEngine::get_fee(&FeeCategory::BridgeLarge), Error::<Test>::UnknownTransactionType);
+        assert!(!FeeEngine::check_fee(&FeeCategory::BridgeLarge, 1_000));
+
+        assert_ok!(FeeEngine::update_fee(RuntimeOrigin::root(), FeeCategory::BridgeLarge, 5));
+        assert_eq!(FeeEngine::get_fee(&FeeCategory::BridgeLarge), Ok(5));
+        assert!(FeeEngine::check_fee(&FeeCategory::BridgeLarge, 5));
+    });
+}
+
+#[test]
+fn migration_v1_keeps_known_categories_and_drops_the_rest() {
+    use crate::migrations::v1;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<FeeEngine>();
+        v1::FixedFees::<Test>::insert(5, 10);
+        v1::FixedFees::<Test>::insert(16, 20);
+        v1::FixedFees::<Test>::insert(200, 30);
+
+        v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(FeeEngine::fixed_fees(FeeCategory::DexTrading), Some(10));
+        assert_eq!(FeeEngine::fixed_fees(FeeCategory::GovernanceProposal), Some(20));
+        assert_eq!(v1::FixedFees::<Test>::get(200), None);
+        assert_eq!(FeeEngine::on_chain_storage_version(), 1);
+    });
+}
+
+#[test]
+fn migration_v2_moves_fee_totals_into_accrued_fees() {
+    use crate::migrations::v2;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<FeeEngine>();
+        v2::FounderFees::<Test>::put(15);
+        v2::DaoFees::<Test>::put(85);
+        crate::AccruedFees::<Test>::insert(DAO, 5);
+
+        v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER), 15);
+        assert_eq!(FeeEngine::accrued_fees(DAO), 90);
+        assert!(!v2::FounderFees::<Test>::exists());
+        assert!(!v2::DaoFees::<Test>::exists());
+        assert_eq!(FeeEngine::on_chain_storage_version(), 2);
+    });
+}
+
+#[test]
+fn genesis_sets_the_fee_table() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(FeeEngine::get_fee(&FeeCategory::GasFee), Ok(GAS_FEE));
+        assert_eq!(FeeEngine::fixed_fees(FeeCategory::DexTrading), None);
+
+        // Fees are not seeded lazily
+        FeeEngine::on_initialize(2);
+        assert_eq!(FeeEngine::fixed_fees(FeeCategory::DexTrading), None);
+    });
+}
+
+fn thresholds(values: &[u64]) -> TierThresholdsOf<Test> {
+    values.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn set_tier_thresholds_validates_thresholds() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeEngine::set_tier_thresholds(RuntimeOrigin::signed(FOUNDER), FeeTierGroup::Pool, thresholds(&[10, 100])),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            FeeEngine::set_tier_thresholds(RuntimeOrigin::root(), FeeTierGroup::Pool, thresholds(&[10])),
+            Error::<Test>::InvalidTierThresholds
+        );
+        assert_noop!(
+            FeeEngine::set_tier_thresholds(RuntimeOrigin::root(), FeeTierGroup::Pool, thresholds(&[100, 100])),
+            Error::<Test>::InvalidTierThresholds
+        );
+
+        assert_ok!(FeeEngine::set_tier_thresholds(RuntimeOrigin::root(), FeeTierGroup::Pool, thresholds(&[10, 100])));
+        System::assert_last_event(
+            Event::TierThresholdsUpdated { group: FeeTierGroup::Pool, thresholds: thresholds(&[10, 100]) }.into(),
+        );
+    });
+}
+
+#[test]
+fn tiers_are_resolved_from_thresholds() {
+    new_test_ext().execute_with(|| {
+        // Without thresholds everything is charged the largest tier
+        assert_eq!(FeeEngine::tier_category(FeeTierGroup::NftMinting, 0), FeeCategory::NftMintingXLarge);
+
+        assert_ok!(FeeEngine::set_tier_thresholds(
+            RuntimeOrigin::root(),
+            FeeTierGroup::NftMinting,
+            thresholds(&[10, 100, 1_000])
+        ));
+        assert_eq!(FeeEngine::tier_category(FeeTierGroup::NftMinting, 9), FeeCategory::NftMintingSmall);
+        assert_eq!(FeeEngine::tier_category(FeeTierGroup::NftMinting, 10), FeeCategory::NftMintingMedium);
+        assert_eq!(FeeEngine::tier_category(FeeTierGroup::NftMinting, 999), FeeCategory::NftMintingLarge);
+        assert_eq!(FeeEngine::tier_category(FeeTierGroup::NftMinting, 1_000), FeeCategory::NftMintingXLarge);
+
+        assert_noop!(
+            FeeEngine::resolve_fee(FeeTierGroup::NftMinting, 50),
+            Error::<Test>::UnknownTransactionType
+        );
+        assert_ok!(FeeEngine::update_fee(RuntimeOrigin::root(), FeeCategory::NftMintingMedium, 4));
+        assert_eq!(FeeEngine::resolve_fee(FeeTierGroup::NftMinting, 50), Ok((FeeCategory::NftMintingMedium, 4)));
+    });
+}
+
+fn discounts(entries: &[(u64, u32)]) -> StakingDiscountsOf<Test> {
+    entries
+        .iter()
+        .map(|(min_stake, percent)| (*min_stake, Permill::from_percent(*percent)))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn set_staking_discounts_validates_tiers() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeEngine::set_staking_discounts(RuntimeOrigin::signed(FOUNDER), discounts(&[(100, 10)])),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            FeeEngine::set_staking_discounts(RuntimeOrigin::root(), discounts(&[(100, 10), (100, 25)])),
+            Error::<Test>::InvalidStakingDiscounts
+        );
+        assert_noop!(
+            FeeEngine::set_staking_discounts(RuntimeOrigin::root(), discounts(&[(100, 25), (1_000, 10)])),
+            Error::<Test>::InvalidStakingDiscounts
+        );
+
+        assert_ok!(FeeEngine::set_staking_discounts(RuntimeOrigin::root(), discounts(&[(100, 10), (1_000, 25)])));
+        System::assert_last_event(
+            Event::StakingDiscountsUpdated { discounts: discounts(&[(100, 10), (1_000, 25)]) }.into(),
+        );
+    });
+}
+
+#[test]
+fn stakers_pay_discounted_fees() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FeeEngine::set_staking_discounts(RuntimeOrigin::root(), discounts(&[(100, 10), (1_000, 25)])));
+
+        // Below the first tier the full fee is charged
+        Stakes::set(vec![(ALICE, 99)]);
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+        System::assert_last_event(
+            Event::FeeCollected { payer: ALICE, category: FeeCategory::DexTrading, amount: 1_000, discount: 0 }
+                .into(),
+        );
+
+        Stakes::set(vec![(ALICE, 100)]);
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+        System::assert_last_event(
+            Event::FeeCollected { payer: ALICE, category: FeeCategory::DexTrading, amount: 900, discount: 100 }
+                .into(),
+        );
+
+        Stakes::set(vec![(ALICE, 5_000)]);
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+        System::assert_last_event(
+            Event::FeeCollected { payer: ALICE, category: FeeCategory::DexTrading, amount: 750, discount: 250 }
+                .into(),
+        );
+
+        assert_eq!(Balances::free_balance(ALICE), 10_000 - 1_000 - 900 - 750);
+        assert_eq!(FeeEngine::total_fees_collected(), 2_650);
+        assert_fee_account_backs_owed_fees();
+    });
+}
+
+#[test]
+fn fee_quotes_follow_the_fee_table() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FeeEngine::update_fee(RuntimeOrigin::root(), FeeCategory::DexTrading, 7));
+
+        assert_eq!(
+            FeeEngine::fee_schedule(),
+            vec![(FeeCategory::GasFee, GAS_FEE), (FeeCategory::DexTrading, 7)]
+        );
+
+        // The mock maps plain remarks to no fee category
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        assert_eq!(FeeEngine::call_fee(&ALICE, &call), None);
+    });
+}
+
+#[test]
+fn call_fee_resolves_registered_calls_to_the_discounted_fee() {
+    new_test_ext().execute_with(|| {
+        let call = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+        // The call has a fee type, but no fee is registered for it yet
+        assert_eq!(FeeEngine::call_fee(&ALICE, &call), None);
+
+        assert_ok!(FeeEngine::update_fee(RuntimeOrigin::root(), FeeCategory::DexTrading, 1_000));
+        assert_eq!(FeeEngine::call_fee(&ALICE, &call), Some((FeeCategory::DexTrading, 1_000)));
+
+        assert_ok!(FeeEngine::set_staking_discounts(
+            RuntimeOrigin::root(),
+            discounts(&[(100, 10), (1_000, 25)])
+        ));
+        Stakes::set(vec![(ALICE, 1_000)]);
+        assert_eq!(FeeEngine::call_fee(&ALICE, &call), Some((FeeCategory::DexTrading, 750)));
+        assert_eq!(FeeEngine::call_fee(&BOB, &call), Some((FeeCategory::DexTrading, 1_000)));
+    });
+}
+
+#[test]
+fn revenue_is_bucketed_per_era_and_category() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 500));
+        FeeEngine::record_fee(&ALICE, FeeCategory::GasFee, 30);
+        assert_eq!(
+            FeeEngine::era_revenue_by_category(0),
+            vec![(FeeCategory::GasFee, 30), (FeeCategory::DexTrading, 1_500)]
+        );
+
+        FeeEngine::on_initialize(ERA_LENGTH - 1);
+        assert_eq!(FeeEngine::current_revenue_era(), 0);
+        FeeEngine::on_initialize(ERA_LENGTH);
+        assert_eq!(FeeEngine::current_revenue_era(), 1);
+        System::assert_last_event(Event::RevenueEraClosed { era: 0 }.into());
+
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::PoolOperations, 200));
+        assert_eq!(FeeEngine::era_revenue_by_category(1), vec![(FeeCategory::PoolOperations, 200)]);
+        assert_eq!(FeeEngine::era_revenue(0, FeeCategory::DexTrading), 1_500);
+    });
+}
+
+#[test]
+fn eras_outside_retention_are_pruned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+
+        // Two eras are retained, so era 0 survives the start of era 1 but not of era 2
+        FeeEngine::on_initialize(ERA_LENGTH);
+        assert_eq!(FeeEngine::era_revenue(0, FeeCategory::DexTrading), 1_000);
+        FeeEngine::on_initialize(2 * ERA_LENGTH);
+        assert_eq!(FeeEngine::current_revenue_era(), 2);
+        assert!(FeeEngine::era_revenue_by_category(0).is_empty());
+        // Totals are unaffected by pruning
+        assert_eq!(FeeEngine::total_fees_collected(), 1_000);
+    });
+}
+
+#[test]
+fn dao_fees_are_swept_into_the_treasury() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FeeEngine::collect_fee(&ALICE, FeeCategory::DexTrading, 1_000));
+
+        FeeEngine::on_initialize(SWEEP_PERIOD - 1);
+        assert_eq!(FeeEngine::accrued_fees(DAO), 850);
+
+        FeeEngine::on_initialize(SWEEP_PERIOD);
+        System::assert_last_event(Event::DaoFeesSwept { amount: 850 }.into());
+        assert_eq!(Balances::free_balance(TREASURY), 850);
+        assert_eq!(FeeEngine::accrued_fees(DAO), 0);
+        // Other beneficiaries still withdraw their share
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER), 150);
+        assert_fee_account_backs_owed_fees();
+    });
+}
+
+// Run `ChargeFixedFee` for `call` signed by `who` up to dispatch.
+fn charge_fixed_fee(who: u64, call: &RuntimeCall) -> Result<(), TransactionValidityError> {
+    ChargeFixedFee::<Test>::new()
+        .validate_and_prepare(RuntimeOrigin::signed(who), call, &DispatchInfo::default(), 0, 0)
+        .map(|_| ())
+}
+
+#[test]
+fn fixed_fee_extension_charges_the_discounted_fee() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FeeEngine::update_fee(RuntimeOrigin::root(), FeeCategory::DexTrading, 1_000));
+        assert_ok!(FeeEngine::set_staking_discounts(
+            RuntimeOrigin::root(),
+            discounts(&[(100, 10)])
+        ));
+        Stakes::set(vec![(ALICE, 100)]);
+
+        let call = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+        assert_eq!(ChargeFixedFee::<Test>::new().weight(&call), <() as WeightInfo>::charge_fixed_fee());
+        assert_ok!(charge_fixed_fee(ALICE, &call));
+
+        System::assert_last_event(
+            Event::FeeCollected { payer: ALICE, category: FeeCategory::DexTrading, amount: 900, discount: 100 }
+                .into(),
+        );
+        assert_eq!(Balances::free_balance(ALICE), 9_100);
+        assert_fee_account_backs_owed_fees();
+    });
+}
+
+#[test]
+fn fixed_fee_extension_ignores_calls_without_fee_type() {
+    new_test_ext().execute_with(|| {
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        assert_eq!(ChargeFixedFee::<Test>::new().weight(&call), Weight::zero());
+
+        // Even an account without funds only pays gas
+        assert_ok!(charge_fixed_fee(BOB, &call));
+        assert_eq!(Balances::free_balance(ALICE), 10_000);
+        assert_eq!(FeeEngine::total_fees_collected(), 0);
+    });
+}
+
+#[test]
+fn fixed_fee_extension_rejects_unregistered_fees() {
+    new_test_ext().execute_with(|| {
+        // Pool operations have a fee type but no registered fee
+        let call = RuntimeCall::System(frame_system::Call::set_heap_pages { pages: 1 });
+        assert_eq!(charge_fixed_fee(ALICE, &call), Err(InvalidTransaction::Payment.into()));
+        assert_eq!(Balances::free_balance(ALICE), 10_000);
+    });
+}
+
+#[test]
+fn fixed_fee_extension_rejects_payers_without_funds() {
+    new_test_ext().execute_with(|| {
+        let call = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+        assert_ok!(FeeEngine::update_fee(RuntimeOrigin::root(), FeeCategory::DexTrading, 1_000));
+        assert_eq!(charge_fixed_fee(BOB, &call), Err(InvalidTransaction::Payment.into()));
+
+        // The payer has to stay alive after the fee
+        assert_ok!(FeeEngine::update_fee(RuntimeOrigin::root(), FeeCategory::DexTrading, 10_000));
+        assert_eq!(charge_fixed_fee(ALICE, &call), Err(InvalidTransaction::Payment.into()));
+        assert_eq!(Balances::free_balance(ALICE), 10_000);
+        assert_eq!(FeeEngine::total_fees_collected(), 0);
+    });
+}
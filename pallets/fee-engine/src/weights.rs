@@ -38,8 +38,14 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_fee_engine`.
 pub trait WeightInfo {
 	fn update_fee() -> Weight;
-	fn withdraw_founder_fees() -> Weight;
-	fn withdraw_dao_fees() -> Weight;
+	fn withdraw_fees() -> Weight;
+	fn set_fee_schedule(n: u32, ) -> Weight;
+	fn announce_fee_schedule(n: u32, ) -> Weight;
+	fn cancel_fee_schedule() -> Weight;
+	fn set_fee_split() -> Weight;
+	fn set_tier_thresholds() -> Weight;
+	fn set_staking_discounts() -> Weight;
+	fn charge_fixed_fee() -> Weight;
 }
 
 /// Weights for `pallet_fee_engine` using the Substrate node and recommended hardware.
@@ -57,32 +63,240 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `FeeEngine::FounderFees` (r:1 w:1)
-	/// Proof: `FeeEngine::FounderFees` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Accounts` (r:1 w:1)
-	/// Proof: `Balances::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn withdraw_founder_fees() -> Weight {
+	/// Storage: `FeeEngine::AccruedFees` (r:1 w:1)
+	/// Proof: `FeeEngine::AccruedFees` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_fees() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `3594`
-		//  Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(25_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3594))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Estimated: `6196`
+		//  Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: `FeeEngine::DaoFees` (r:1 w:1)
-	/// Proof: `FeeEngine::DaoFees` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Accounts` (r:1 w:1)
-	/// Proof: `Balances::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn withdraw_dao_fees() -> Weight {
+	/// Storage: `FeeEngine::FixedFees` (r:0 w:32)
+	/// Proof: `FeeEngine::FixedFees` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn set_fee_schedule(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `FeeEngine::PendingFeeSchedule` (r:0 w:1)
+	/// Proof: `FeeEngine::PendingFeeSchedule` (`max_values`: Some(1), `max_size`: Some(550), added: 1045, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn announce_fee_schedule(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::PendingFeeSchedule` (r:1 w:1)
+	/// Proof: `FeeEngine::PendingFeeSchedule` (`max_values`: Some(1), `max_size`: Some(550), added: 1045, mode: `MaxEncodedLen`)
+	fn cancel_fee_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2035`
+		//  Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::FeeSplit` (r:0 w:1)
+	/// Proof: `FeeEngine::FeeSplit` (`max_values`: Some(1), `max_size`: Some(289), added: 784, mode: `MaxEncodedLen`)
+	fn set_fee_split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::TierThresholds` (r:0 w:1)
+	/// Proof: `FeeEngine::TierThresholds` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn set_tier_thresholds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::StakingDiscounts` (r:0 w:1)
+	/// Proof: `FeeEngine::StakingDiscounts` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	fn set_staking_discounts() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `3594`
-		//  Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(25_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3594))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Estimated: `0`
+		//  Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::FixedFees` (r:1 w:0)
+	/// Proof: `FeeEngine::FixedFees` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::StakingDiscounts` (r:1 w:0)
+	/// Proof: `FeeEngine::StakingDiscounts` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::StakedBalances` (r:1 w:0)
+	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Balances` (r:2 w:2)
+	/// Proof: `FiStablecoin::Balances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::TotalFeesCollected` (r:1 w:1)
+	/// Proof: `FeeEngine::TotalFeesCollected` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::CurrentRevenueEra` (r:1 w:0)
+	/// Proof: `FeeEngine::CurrentRevenueEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::EraRevenue` (r:1 w:1)
+	/// Proof: `FeeEngine::EraRevenue` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::FeeSplit` (r:1 w:0)
+	/// Proof: `FeeEngine::FeeSplit` (`max_values`: Some(1), `max_size`: Some(289), added: 784, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::AccruedFees` (r:2 w:2)
+	/// Proof: `FeeEngine::AccruedFees` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn charge_fixed_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		//  Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
-}
\ No newline at end of file
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `FeeEngine::FixedFees` (r:1 w:1)
+	/// Proof: `FeeEngine::FixedFees` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn update_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3514`
+		//  Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3514))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::AccruedFees` (r:1 w:1)
+	/// Proof: `FeeEngine::AccruedFees` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `6196`
+		//  Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `FeeEngine::FixedFees` (r:0 w:32)
+	/// Proof: `FeeEngine::FixedFees` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn set_fee_schedule(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `FeeEngine::PendingFeeSchedule` (r:0 w:1)
+	/// Proof: `FeeEngine::PendingFeeSchedule` (`max_values`: Some(1), `max_size`: Some(550), added: 1045, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn announce_fee_schedule(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::PendingFeeSchedule` (r:1 w:1)
+	/// Proof: `FeeEngine::PendingFeeSchedule` (`max_values`: Some(1), `max_size`: Some(550), added: 1045, mode: `MaxEncodedLen`)
+	fn cancel_fee_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2035`
+		//  Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::FeeSplit` (r:0 w:1)
+	/// Proof: `FeeEngine::FeeSplit` (`max_values`: Some(1), `max_size`: Some(289), added: 784, mode: `MaxEncodedLen`)
+	fn set_fee_split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::TierThresholds` (r:0 w:1)
+	/// Proof: `FeeEngine::TierThresholds` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn set_tier_thresholds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::StakingDiscounts` (r:0 w:1)
+	/// Proof: `FeeEngine::StakingDiscounts` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	fn set_staking_discounts() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `FeeEngine::FixedFees` (r:1 w:0)
+	/// Proof: `FeeEngine::FixedFees` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::StakingDiscounts` (r:1 w:0)
+	/// Proof: `FeeEngine::StakingDiscounts` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	/// Storage: `CreateToken::StakedBalances` (r:1 w:0)
+	/// Proof: `CreateToken::StakedBalances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Balances` (r:2 w:2)
+	/// Proof: `FiStablecoin::Balances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::TotalFeesCollected` (r:1 w:1)
+	/// Proof: `FeeEngine::TotalFeesCollected` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::CurrentRevenueEra` (r:1 w:0)
+	/// Proof: `FeeEngine::CurrentRevenueEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::EraRevenue` (r:1 w:1)
+	/// Proof: `FeeEngine::EraRevenue` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::FeeSplit` (r:1 w:0)
+	/// Proof: `FeeEngine::FeeSplit` (`max_values`: Some(1), `max_size`: Some(289), added: 784, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::AccruedFees` (r:2 w:2)
+	/// Proof: `FeeEngine::AccruedFees` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn charge_fixed_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		//  Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+}
@@ -0,0 +1,17 @@
+//! Runtime API definition for the FI stablecoin pallet.
+//!
+//! Lets explorers and dashboards report how well the FI supply is backed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use sp_runtime::FixedU128;
+
+sp_api::decl_runtime_apis! {
+    /// Reports the reserves backing `pallet_fi_stablecoin`.
+    pub trait FiStablecoinApi {
+        /// Oracle value of all collateral held for FI, divided by the FI supply.
+        ///
+        /// `None` without FI in circulation or without a fresh price for every collateral asset.
+        fn collateralization_ratio() -> Option<FixedU128>;
+    }
+}
@@ -0,0 +1,342 @@
+//! `fungible` traits for the FI ledger.
+//!
+//! FI is non-transferable between users and only issued against collateral, so the traits are
+//! restricted accordingly: FI can only be transferred when one side is one of
+//! [`Config::SystemAccounts`], and it cannot be minted, burned, shelved or restored through them.
+//! Issuance stays with the mint, burn and vault calls, which keep the proof-of-reserves
+//! invariants intact. For the same reason the [`Unbalanced`] balance and issuance setters are
+//! refused, and every transfer, hold and release moves FI directly between the free and held
+//! balances instead of going through them.
+//!
+//! Any account can put its own FI on hold, but held FI follows the transfer rule above and
+//! cannot be burned.
+
+use crate::{Balances, BalanceOf, Config, Event, Holds, Pallet, TotalHeld, TotalSupply};
+use frame_support::{
+    ensure,
+    traits::{
+        fungible::{hold, Dust, Inspect, Mutate, Unbalanced},
+        tokens::{
+            DepositConsequence, Fortitude, Precision, Preservation, Provenance, Restriction,
+            WithdrawConsequence,
+        },
+        Contains,
+    },
+};
+use sp_runtime::{
+    traits::{CheckedAdd, CheckedSub, Saturating, Zero},
+    ArithmeticError, DispatchError, DispatchResult, TokenError,
+};
+
+impl<T: Config> Pallet<T> {
+    /// Ensure FI may move from `source` to `dest`, which needs one of them to be a system account.
+    fn ensure_system_transfer(source: &T::AccountId, dest: &T::AccountId) -> DispatchResult {
+        ensure!(
+            T::SystemAccounts::contains(source) || T::SystemAccounts::contains(dest),
+            TokenError::Blocked
+        );
+        Ok(())
+    }
+
+    /// The amount of the FI `who` holds for `reason` that can be taken for a request of `amount`.
+    fn held_amount(
+        reason: &T::RuntimeHoldReason,
+        who: &T::AccountId,
+        amount: BalanceOf<T>,
+        precision: Precision,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let held = Holds::<T>::get(who, reason);
+        match precision {
+            Precision::BestEffort => Ok(amount.min(held)),
+            Precision::Exact => {
+                ensure!(amount <= held, TokenError::FundsUnavailable);
+                Ok(amount)
+            },
+        }
+    }
+
+    /// Add `amount` to the FI `who` holds for `reason`.
+    fn add_to_hold(reason: &T::RuntimeHoldReason, who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+        let held = Holds::<T>::get(who, reason).checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+        let total_held = TotalHeld::<T>::get(who).checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+        Holds::<T>::insert(who, reason, held);
+        TotalHeld::<T>::insert(who, total_held);
+        Ok(())
+    }
+
+    /// Remove `amount`, as returned by [`Self::held_amount`], from the FI `who` holds for `reason`.
+    fn reduce_hold(reason: &T::RuntimeHoldReason, who: &T::AccountId, amount: BalanceOf<T>) {
+        let held = Holds::<T>::get(who, reason).saturating_sub(amount);
+        if held.is_zero() {
+            Holds::<T>::remove(who, reason);
+        } else {
+            Holds::<T>::insert(who, reason, held);
+        }
+        let total_held = TotalHeld::<T>::get(who).saturating_sub(amount);
+        if total_held.is_zero() {
+            TotalHeld::<T>::remove(who);
+        } else {
+            TotalHeld::<T>::insert(who, total_held);
+        }
+    }
+}
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+    type Balance = BalanceOf<T>;
+
+    fn total_issuance() -> Self::Balance {
+        TotalSupply::<T>::get()
+    }
+
+    fn minimum_balance() -> Self::Balance {
+        Zero::zero()
+    }
+
+    fn total_balance(who: &T::AccountId) -> Self::Balance {
+        Balances::<T>::get(who).saturating_add(TotalHeld::<T>::get(who))
+    }
+
+    fn balance(who: &T::AccountId) -> Self::Balance {
+        Balances::<T>::get(who)
+    }
+
+    fn reducible_balance(who: &T::AccountId, _preservation: Preservation, _force: Fortitude) -> Self::Balance {
+        Balances::<T>::get(who)
+    }
+
+    fn can_deposit(who: &T::AccountId, amount: Self::Balance, provenance: Provenance) -> DepositConsequence {
+        if amount.is_zero() {
+            return DepositConsequence::Success;
+        }
+
+        // FI is only issued against collateral
+        if provenance == Provenance::Minted {
+            return DepositConsequence::Blocked;
+        }
+
+        match Balances::<T>::get(who).checked_add(&amount) {
+            Some(_) => DepositConsequence::Success,
+            None => DepositConsequence::Overflow,
+        }
+    }
+
+    fn can_withdraw(who: &T::AccountId, amount: Self::Balance) -> WithdrawConsequence<Self::Balance> {
+        if amount.is_zero() {
+            return WithdrawConsequence::Success;
+        }
+
+        if TotalSupply::<T>::get().checked_sub(&amount).is_none() {
+            return WithdrawConsequence::Underflow;
+        }
+        if Balances::<T>::get(who) < amount {
+            return WithdrawConsequence::BalanceLow;
+        }
+
+        WithdrawConsequence::Success
+    }
+}
+
+impl<T: Config> Unbalanced<T::AccountId> for Pallet<T> {
+    fn handle_dust(_dust: Dust<T::AccountId, Self>) {
+        // The minimum balance is zero, so there is never any dust.
+    }
+
+    fn write_balance(_who: &T::AccountId, _amount: Self::Balance) -> Result<Option<Self::Balance>, DispatchError> {
+        // Writing balances directly would bypass the collateral accounting
+        Err(TokenError::Unsupported.into())
+    }
+
+    fn set_total_issuance(_amount: Self::Balance) {
+        // The supply only changes with the collateral backing it, through the pallet's calls
+    }
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+    fn mint_into(_who: &T::AccountId, _amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+        Err(TokenError::Unsupported.into())
+    }
+
+    fn burn_from(
+        _who: &T::AccountId,
+        _amount: Self::Balance,
+        _preservation: Preservation,
+        _precision: Precision,
+        _force: Fortitude,
+    ) -> Result<Self::Balance, DispatchError> {
+        Err(TokenError::Unsupported.into())
+    }
+
+    fn shelve(_who: &T::AccountId, _amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+        Err(TokenError::Unsupported.into())
+    }
+
+    fn restore(_who: &T::AccountId, _amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+        Err(TokenError::Unsupported.into())
+    }
+
+    fn transfer(
+        source: &T::AccountId,
+        dest: &T::AccountId,
+        amount: Self::Balance,
+        _preservation: Preservation,
+    ) -> Result<Self::Balance, DispatchError> {
+        Self::ensure_system_transfer(source, dest)?;
+        if amount.is_zero() || source == dest {
+            return Ok(amount);
+        }
+
+        let source_balance = Balances::<T>::get(source)
+            .checked_sub(&amount)
+            .ok_or(TokenError::FundsUnavailable)?;
+        let dest_balance = Balances::<T>::get(dest)
+            .checked_add(&amount)
+            .ok_or(ArithmeticError::Overflow)?;
+        Balances::<T>::insert(source, source_balance);
+        Balances::<T>::insert(dest, dest_balance);
+
+        Self::done_transfer(source, dest, amount);
+        Ok(amount)
+    }
+
+    fn done_transfer(source: &T::AccountId, dest: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::Transferred { from: source.clone(), to: dest.clone(), amount });
+    }
+}
+
+impl<T: Config> hold::Inspect<T::AccountId> for Pallet<T> {
+    type Reason = T::RuntimeHoldReason;
+
+    fn total_balance_on_hold(who: &T::AccountId) -> Self::Balance {
+        TotalHeld::<T>::get(who)
+    }
+
+    fn balance_on_hold(reason: &Self::Reason, who: &T::AccountId) -> Self::Balance {
+        Holds::<T>::get(who, reason)
+    }
+}
+
+impl<T: Config> hold::Unbalanced<T::AccountId> for Pallet<T> {
+    fn set_balance_on_hold(_reason: &Self::Reason, _who: &T::AccountId, _amount: Self::Balance) -> DispatchResult {
+        // Held FI only moves through `hold::Mutate`, which keeps the free balance in step
+        Err(TokenError::Unsupported.into())
+    }
+}
+
+impl<T: Config> hold::Mutate<T::AccountId> for Pallet<T> {
+    fn hold(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+        let balance = Balances::<T>::get(who).checked_sub(&amount).ok_or(TokenError::FundsUnavailable)?;
+        Self::add_to_hold(reason, who, amount)?;
+        Balances::<T>::insert(who, balance);
+
+        Self::done_hold(reason, who, amount);
+        Ok(())
+    }
+
+    fn release(
+        reason: &Self::Reason,
+        who: &T::AccountId,
+        amount: Self::Balance,
+        precision: Precision,
+    ) -> Result<Self::Balance, DispatchError> {
+        let amount = Self::held_amount(reason, who, amount, precision)?;
+        let balance = Balances::<T>::get(who).checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+        Self::reduce_hold(reason, who, amount);
+        Balances::<T>::insert(who, balance);
+
+        Self::done_release(reason, who, amount);
+        Ok(amount)
+    }
+
+    fn burn_held(
+        _reason: &Self::Reason,
+        _who: &T::AccountId,
+        _amount: Self::Balance,
+        _precision: Precision,
+        _force: Fortitude,
+    ) -> Result<Self::Balance, DispatchError> {
+        Err(TokenError::Unsupported.into())
+    }
+
+    fn transfer_on_hold(
+        reason: &Self::Reason,
+        source: &T::AccountId,
+        dest: &T::AccountId,
+        amount: Self::Balance,
+        precision: Precision,
+        mode: Restriction,
+        _force: Fortitude,
+    ) -> Result<Self::Balance, DispatchError> {
+        Self::ensure_system_transfer(source, dest)?;
+        let amount = Self::held_amount(reason, source, amount, precision)?;
+
+        match mode {
+            Restriction::OnHold => {
+                Self::add_to_hold(reason, dest, amount)?;
+                Self::reduce_hold(reason, source, amount);
+            },
+            Restriction::Free => {
+                let dest_balance = Balances::<T>::get(dest).checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+                Self::reduce_hold(reason, source, amount);
+                Balances::<T>::insert(dest, dest_balance);
+            },
+        }
+
+        Self::done_transfer_on_hold(reason, source, dest, amount);
+        Ok(amount)
+    }
+
+    fn transfer_and_hold(
+        reason: &Self::Reason,
+        source: &T::AccountId,
+        dest: &T::AccountId,
+        amount: Self::Balance,
+        precision: Precision,
+        _expendability: Preservation,
+        _force: Fortitude,
+    ) -> Result<Self::Balance, DispatchError> {
+        Self::ensure_system_transfer(source, dest)?;
+        let source_balance = Balances::<T>::get(source);
+        let amount = match precision {
+            Precision::BestEffort => amount.min(source_balance),
+            Precision::Exact => amount,
+        };
+        let source_balance = source_balance.checked_sub(&amount).ok_or(TokenError::FundsUnavailable)?;
+        Self::add_to_hold(reason, dest, amount)?;
+        Balances::<T>::insert(source, source_balance);
+
+        Self::done_transfer_and_hold(reason, source, dest, amount);
+        Ok(amount)
+    }
+
+    fn done_hold(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::Held { reason: reason.clone(), account: who.clone(), amount });
+    }
+
+    fn done_release(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) {
+        Self::deposit_event(Event::Released { reason: reason.clone(), account: who.clone(), amount });
+    }
+
+    fn done_transfer_on_hold(
+        reason: &Self::Reason,
+        source: &T::AccountId,
+        dest: &T::AccountId,
+        amount: Self::Balance,
+    ) {
+        Self::deposit_event(Event::HeldTransferred {
+            reason: reason.clone(),
+            from: source.clone(),
+            to: dest.clone(),
+            amount,
+        });
+    }
+
+    fn done_transfer_and_hold(
+        reason: &Self::Reason,
+        source: &T::AccountId,
+        dest: &T::AccountId,
+        transferred: Self::Balance,
+    ) {
+        Self::done_transfer(source, dest, transferred);
+        Self::done_hold(reason, dest, transferred);
+    }
+}
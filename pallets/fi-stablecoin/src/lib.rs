@@ -3,6 +3,35 @@
 //! This pallet implements the FI stablecoin for the CREATEFI blockchain.
 //! FI is a stablecoin backed 1:1 by top stablecoins (USDT, USDC, DAI, BUSD, TUSD).
 //! It is non-transferable between users and only used for gas and fee payments.
+//!
+//! Each backing stablecoin is registered as a collateral asset with its own debt ceiling and
+//! enable switch. Deposits are tracked per account and per asset, and the FI outstanding against
+//! each asset is tracked per asset, so that every FI in circulation can be traced back to the
+//! stablecoin that backs it. FI minted 1:1 is also tracked per account and per asset, and can
+//! only be redeemed for the asset it was minted against, so a depegged stablecoin can never drain
+//! the reserve of another.
+//!
+//! Besides the 1:1 mint path, users can open over-collateralized vaults (CDPs) that lock a
+//! collateral asset and carry FI debt, subject to a minimum collateral ratio. Vaults that fall
+//! below the liquidation ratio are flagged by a per-block sweep and can be liquidated by any
+//! keeper, who repays the debt and receives the seized collateral plus a reward.
+//!
+//! The 1:1 mint path acts as a peg stability module: each collateral asset carries its own mint
+//! and redeem fee and a cap on the reserve held for it, and a global debt ceiling bounds all FI
+//! outstanding against collateral. As FI only redeems the asset it was minted against, the reserve
+//! cap bounds the FI a depegged asset can put into circulation while the reserves of the other
+//! assets stay whole. Peg stability fees are paid in FI into the fee engine account
+//! and split between the fee beneficiaries like any other fee.
+//!
+//! Proof of reserves: every block with spare weight, `on_idle` checks that the FI supply is
+//! backed by collateral issuance and that the pallet account holds every collateral asset it
+//! accounts for. A broken invariant pauses minting until [`Config::CollateralAdminOrigin`]
+//! resumes it. Registered auditors attest the off-chain reserves of each backing stablecoin.
+//!
+//! Transaction fees are paid in FI through [`payment::FiCurrencyAdapter`].
+//!
+//! Other pallets can inspect and hold FI balances through the `fungible` traits implemented in
+//! [`fungible`]. FI can only be transferred to or from [`Config::SystemAccounts`] that way.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -12,6 +41,15 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod fungible;
+pub mod migrations;
+pub mod payment;
 pub mod weights;
 pub use weights::*;
 
@@ -20,10 +58,21 @@ pub mod pallet {
     use super::*;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, ExistenceRequirement, WithdrawReasons},
+        traits::{
+            fungibles::{self, Mutate as FungiblesMutate},
+            tokens::Preservation,
+            Contains, Currency,
+        },
+        PalletId,
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero};
+    use codec::DecodeWithMemTracking;
+    use pallet_fee_engine::{FeeCategory, FeeEngineInterface};
+    use pallet_oracle::PriceProvider;
+    use sp_runtime::{
+        traits::{AccountIdConversion, Bounded, CheckedAdd, CheckedSub, Saturating, Zero},
+        FixedPointNumber, FixedU128, Permill,
+    };
 
     /// The pallet's configuration trait.
     #[pallet::config]
@@ -33,6 +82,51 @@ pub mod pallet {
         
         /// The currency type for handling balances.
         type Currency: Currency<Self::AccountId>;
+
+        /// The multi-asset ledger holding the stablecoins accepted as collateral.
+        type Assets: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+            + fungibles::Mutate<Self::AccountId>;
+
+        /// The origin allowed to manage the collateral asset registry.
+        type CollateralAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The pallet id, used to derive the account holding deposited collateral.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// The fee engine charging vault operation fees.
+        type FeeEngine: FeeEngineInterface<Self::AccountId, BalanceOf<Self>>;
+
+        /// The minimum collateral value to debt ratio a vault must keep (e.g. 150%).
+        #[pallet::constant]
+        type MinCollateralRatio: Get<FixedU128>;
+
+        /// The maximum number of vaults a single account can own.
+        #[pallet::constant]
+        type MaxVaultsPerAccount: Get<u32>;
+
+        /// Source of collateral prices in FI, keyed by collateral asset symbol.
+        type PriceProvider: PriceProvider<AssetSymbol>;
+
+        /// The collateral value to debt ratio below which a vault can be liquidated (e.g. 110%).
+        #[pallet::constant]
+        type LiquidationRatio: Get<FixedU128>;
+
+        /// The share of the repaid debt seized as a penalty on liquidation.
+        #[pallet::constant]
+        type LiquidationPenalty: Get<Permill>;
+
+        /// The share of the repaid debt paid to the keeper on top of the repaid amount.
+        #[pallet::constant]
+        type KeeperReward: Get<Permill>;
+
+        /// The account receiving liquidation penalties.
+        #[pallet::constant]
+        type LiquidationBeneficiary: Get<Self::AccountId>;
+
+        /// The maximum number of vaults checked by the liquidation sweep each block.
+        #[pallet::constant]
+        type MaxLiquidationChecks: Get<u32>;
         
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
@@ -44,13 +138,96 @@ pub mod pallet {
         /// The maximum amount of FI that can be minted per transaction.
         #[pallet::constant]
         type MaxMintAmount: Get<BalanceOf<Self>>;
+
+        /// System accounts, such as the fee engine and the DAO treasury, that may send or
+        /// receive FI through the `fungible` traits. FI never moves between two other accounts.
+        type SystemAccounts: Contains<Self::AccountId>;
+
+        /// The overarching hold reason, under which other pallets hold FI through the `fungible`
+        /// hold traits.
+        type RuntimeHoldReason: Parameter + Member + MaxEncodedLen;
     }
 
     /// Balance type for this pallet.
     pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    /// Identifier of a collateral asset in the multi-asset ledger.
+    pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+    /// Short ticker of a collateral asset (e.g. `USDT`).
+    pub type AssetSymbol = BoundedVec<u8, ConstU32<32>>;
+
+    /// Registry entry for a stablecoin accepted as FI collateral.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct CollateralAssetInfo<Balance> {
+        /// Ticker of the asset.
+        pub symbol: AssetSymbol,
+        /// Whether new deposits and mints are accepted for this asset.
+        pub enabled: bool,
+        /// Maximum amount of FI that may be outstanding against this asset.
+        pub debt_ceiling: Balance,
+        /// Deposited collateral not yet used to mint FI.
+        pub total_deposited: Balance,
+        /// FI currently outstanding against this asset through 1:1 minting.
+        pub total_issued: Balance,
+        /// Collateral locked in vaults.
+        pub vault_collateral: Balance,
+        /// FI debt carried by vaults.
+        pub vault_debt: Balance,
+        /// Share of the deposited collateral kept as a fee when minting FI.
+        pub mint_fee: Permill,
+        /// Share of the burned FI kept as a fee when redeeming collateral.
+        pub redeem_fee: Permill,
+        /// Maximum FI outstanding against this asset through 1:1 minting.
+        pub reserve_cap: Balance,
+    }
+
+    /// An auditor's attestation of the off-chain reserves of a backing stablecoin.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ReserveAttestation<Balance, BlockNumber> {
+        /// Reserves held off-chain, in units of the backing stablecoin.
+        pub amount: Balance,
+        /// Block at which the attestation was submitted.
+        pub attested_at: BlockNumber,
+    }
+
+    /// A broken proof-of-reserves invariant.
+    #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ReserveBreach<AssetId> {
+        /// The FI supply exceeds the FI issued against collateral plus the unbacked issuance.
+        UnbackedSupply,
+        /// The pallet account holds less of the asset than it accounts for.
+        CustodyShortfall(AssetId),
+    }
+
+    /// Vault identifier.
+    pub type VaultId = u64;
+
+    /// An over-collateralized debt position.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Vault<T: Config> {
+        pub id: VaultId,
+        pub owner: <T as frame_system::Config>::AccountId,
+        pub collateral_asset: AssetIdOf<T>,
+        pub collateral_amount: BalanceOf<T>,
+        pub debt_amount: BalanceOf<T>,
+        pub created_at: BlockNumberFor<T>,
+    }
+
+    /// A change applied to the collateral or debt of a vault.
+    #[derive(Clone, Copy, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Adjustment<Balance> {
+        Increase(Balance),
+        Decrease(Balance),
+    }
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     /// The pallet's storage items.
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Total supply of FI tokens.
@@ -63,46 +240,304 @@ pub mod pallet {
     #[pallet::getter(fn balance_of)]
     pub type Balances<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, BalanceOf<T>, ValueQuery>;
 
-    /// Collateral reserves for each account.
+    /// FI of each account held by other pallets, per hold reason.
+    #[pallet::storage]
+    pub type Holds<T> = StorageDoubleMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Blake2_128Concat, <T as Config>::RuntimeHoldReason, BalanceOf<T>, ValueQuery>;
+
+    /// FI of each account held by other pallets, for any reason.
+    #[pallet::storage]
+    pub type TotalHeld<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Registered collateral assets.
+    #[pallet::storage]
+    #[pallet::getter(fn collateral_asset)]
+    pub type CollateralAssets<T> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, CollateralAssetInfo<BalanceOf<T>>, OptionQuery>;
+
+    /// Collateral reserves for each account, per collateral asset.
     #[pallet::storage]
     #[pallet::getter(fn collateral_of)]
-    pub type Collateral<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, BalanceOf<T>, ValueQuery>;
+    pub type Collateral<T> = StorageDoubleMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Blake2_128Concat, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+    /// FI each account minted 1:1 against each collateral asset and has not redeemed yet.
+    #[pallet::storage]
+    #[pallet::getter(fn redeemable_of)]
+    pub type Redeemable<T> = StorageDoubleMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Blake2_128Concat, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
 
     /// Total collateral in the system.
     #[pallet::storage]
     #[pallet::getter(fn total_collateral)]
     pub type TotalCollateral<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// FI outstanding against all collateral assets, through 1:1 minting and vaults.
+    #[pallet::storage]
+    #[pallet::getter(fn total_debt)]
+    pub type TotalDebt<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Maximum FI outstanding against all collateral assets, through 1:1 minting and vaults.
+    ///
+    /// `None` leaves only the per-asset debt ceilings in place.
+    #[pallet::storage]
+    #[pallet::getter(fn global_debt_ceiling)]
+    pub type GlobalDebtCeiling<T> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+    /// FI issued without collateral, e.g. genesis balances on development chains.
+    #[pallet::storage]
+    #[pallet::getter(fn unbacked_issuance)]
+    pub type UnbackedIssuance<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Whether minting FI against collateral is paused.
+    #[pallet::storage]
+    #[pallet::getter(fn minting_paused)]
+    pub type MintingPaused<T> = StorageValue<_, bool, ValueQuery>;
+
+    /// Accounts allowed to attest off-chain reserves.
+    #[pallet::storage]
+    pub type Auditors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Latest reserve attestation of each auditor, per backing stablecoin.
+    #[pallet::storage]
+    #[pallet::getter(fn reserve_attestation)]
+    pub type ReserveAttestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        ReserveAttestation<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Next vault ID.
+    #[pallet::storage]
+    pub type NextVaultId<T> = StorageValue<_, VaultId, ValueQuery>;
+
+    /// Vaults by ID.
+    #[pallet::storage]
+    #[pallet::getter(fn vaults)]
+    pub type Vaults<T: Config> = StorageMap<_, Blake2_128Concat, VaultId, Vault<T>, OptionQuery>;
+
+    /// Next vault ID to be checked by the liquidation sweep.
+    #[pallet::storage]
+    pub type LiquidationCursor<T> = StorageValue<_, VaultId, ValueQuery>;
+
+    /// Vault IDs owned by each account.
+    #[pallet::storage]
+    #[pallet::getter(fn vaults_of)]
+    pub type VaultsByOwner<T: Config> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, BoundedVec<VaultId, T::MaxVaultsPerAccount>, ValueQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Initial FI balances, e.g. to pay for gas on development chains.
+        pub balances: Vec<(T::AccountId, BalanceOf<T>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (account, amount) in &self.balances {
+                Pallet::<T>::mint_fi_internal(account, *amount)
+                    .expect("genesis FI balances should not overflow the total supply");
+                UnbackedIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_add(*amount));
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
+            Self::sweep_unsafe_vaults()
+        }
+
+        fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let weight = T::WeightInfo::reserve_check();
+            if MintingPaused::<T>::get() || remaining_weight.any_lt(weight) {
+                return T::DbWeight::get().reads(1);
+            }
+
+            if let Err(breach) = Self::check_reserves() {
+                MintingPaused::<T>::put(true);
+                Self::deposit_event(Event::ReserveInvariantBroken { breach });
+                Self::deposit_event(Event::MintingPauseChanged { paused: true });
+            }
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_block_number: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::check_reserves().map_err(|breach| match breach {
+                ReserveBreach::UnbackedSupply => "FI supply is not backed by collateral".into(),
+                ReserveBreach::CustodyShortfall(_) => "pallet account holds less collateral than accounted".into(),
+            })
+        }
+    }
+
     /// Events that functions in this pallet can emit.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// FI tokens were minted.
-        /// [account, amount, collateral_amount]
+        /// [account, asset_id, amount, collateral_amount, fee]
         Minted {
             account: T::AccountId,
+            asset_id: AssetIdOf<T>,
             amount: BalanceOf<T>,
             collateral_amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
         },
         /// FI tokens were burned.
-        /// [account, amount, collateral_returned]
+        /// [account, asset_id, amount, collateral_returned, fee]
         Burned {
             account: T::AccountId,
+            asset_id: AssetIdOf<T>,
             amount: BalanceOf<T>,
             collateral_returned: BalanceOf<T>,
+            fee: BalanceOf<T>,
         },
         /// Collateral was deposited.
-        /// [account, amount]
+        /// [account, asset_id, amount]
         CollateralDeposited {
             account: T::AccountId,
+            asset_id: AssetIdOf<T>,
             amount: BalanceOf<T>,
         },
         /// Collateral was withdrawn.
-        /// [account, amount]
+        /// [account, asset_id, amount]
         CollateralWithdrawn {
             account: T::AccountId,
+            asset_id: AssetIdOf<T>,
+            amount: BalanceOf<T>,
+        },
+        /// A collateral asset was registered.
+        /// [asset_id, symbol, debt_ceiling]
+        CollateralAssetRegistered {
+            asset_id: AssetIdOf<T>,
+            symbol: AssetSymbol,
+            debt_ceiling: BalanceOf<T>,
+        },
+        /// A collateral asset was enabled or disabled.
+        /// [asset_id, enabled]
+        CollateralAssetStatusChanged {
+            asset_id: AssetIdOf<T>,
+            enabled: bool,
+        },
+        /// The debt ceiling of a collateral asset was updated.
+        /// [asset_id, debt_ceiling]
+        DebtCeilingUpdated {
+            asset_id: AssetIdOf<T>,
+            debt_ceiling: BalanceOf<T>,
+        },
+        /// The peg stability parameters of a collateral asset were updated.
+        /// [asset_id, mint_fee, redeem_fee, reserve_cap]
+        PegStabilityParamsUpdated {
+            asset_id: AssetIdOf<T>,
+            mint_fee: Permill,
+            redeem_fee: Permill,
+            reserve_cap: BalanceOf<T>,
+        },
+        /// The global debt ceiling was updated.
+        /// [debt_ceiling]
+        GlobalDebtCeilingUpdated {
+            debt_ceiling: Option<BalanceOf<T>>,
+        },
+        /// A proof-of-reserves invariant broke and minting was paused.
+        /// [breach]
+        ReserveInvariantBroken {
+            breach: ReserveBreach<AssetIdOf<T>>,
+        },
+        /// Minting FI against collateral was paused or resumed.
+        /// [paused]
+        MintingPauseChanged {
+            paused: bool,
+        },
+        /// FI was moved to or from a system account.
+        /// [from, to, amount]
+        Transferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// FI was put on hold.
+        /// [reason, account, amount]
+        Held {
+            reason: T::RuntimeHoldReason,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Held FI was released.
+        /// [reason, account, amount]
+        Released {
+            reason: T::RuntimeHoldReason,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Held FI was moved to or from a system account.
+        /// [reason, from, to, amount]
+        HeldTransferred {
+            reason: T::RuntimeHoldReason,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// An auditor was registered.
+        /// [auditor]
+        AuditorAdded {
+            auditor: T::AccountId,
+        },
+        /// An auditor was removed.
+        /// [auditor]
+        AuditorRemoved {
+            auditor: T::AccountId,
+        },
+        /// An auditor attested the off-chain reserves of a backing stablecoin.
+        /// [auditor, asset_id, amount]
+        ReservesAttested {
+            auditor: T::AccountId,
+            asset_id: AssetIdOf<T>,
             amount: BalanceOf<T>,
         },
+        /// A vault was opened.
+        /// [vault_id, owner, asset_id, collateral, debt]
+        VaultOpened {
+            vault_id: VaultId,
+            owner: T::AccountId,
+            asset_id: AssetIdOf<T>,
+            collateral: BalanceOf<T>,
+            debt: BalanceOf<T>,
+        },
+        /// The collateral or debt of a vault changed.
+        /// [vault_id, collateral, debt]
+        VaultAdjusted {
+            vault_id: VaultId,
+            collateral: BalanceOf<T>,
+            debt: BalanceOf<T>,
+        },
+        /// A vault was closed and its collateral returned.
+        /// [vault_id, owner, collateral_returned, debt_repaid]
+        VaultClosed {
+            vault_id: VaultId,
+            owner: T::AccountId,
+            collateral_returned: BalanceOf<T>,
+            debt_repaid: BalanceOf<T>,
+        },
+        /// A vault fell below the liquidation ratio.
+        /// [vault_id, collateral, debt]
+        VaultUnsafe {
+            vault_id: VaultId,
+            collateral: BalanceOf<T>,
+            debt: BalanceOf<T>,
+        },
+        /// A vault was liquidated.
+        /// [vault_id, owner, keeper, collateral_seized, debt_repaid, keeper_reward, penalty]
+        VaultLiquidated {
+            vault_id: VaultId,
+            owner: T::AccountId,
+            keeper: T::AccountId,
+            collateral_seized: BalanceOf<T>,
+            debt_repaid: BalanceOf<T>,
+            keeper_reward: BalanceOf<T>,
+            penalty: BalanceOf<T>,
+        },
     }
 
     /// Errors that can be returned by this pallet.
@@ -124,18 +559,53 @@ pub mod pallet {
         NoFiBalance,
         /// Account has no collateral.
         NoCollateral,
+        /// The collateral asset is not registered.
+        CollateralAssetNotFound,
+        /// The collateral asset is already registered.
+        CollateralAssetAlreadyRegistered,
+        /// The collateral asset is disabled for deposits and minting.
+        CollateralAssetDisabled,
+        /// Minting would exceed the debt ceiling of the collateral asset.
+        DebtCeilingExceeded,
+        /// Minting would exceed the global debt ceiling.
+        GlobalDebtCeilingExceeded,
+        /// Minting would exceed the reserve cap of the collateral asset.
+        ReserveCapExceeded,
+        /// Minting is paused after a broken proof-of-reserves invariant.
+        MintingPaused,
+        /// The caller is not a registered auditor.
+        NotAuditor,
+        /// The account is already a registered auditor.
+        AuditorAlreadyRegistered,
+        /// Vault does not exist.
+        VaultNotFound,
+        /// Caller does not own the vault.
+        NotVaultOwner,
+        /// The vault would fall below the minimum collateral ratio.
+        BelowMinCollateralRatio,
+        /// Repayment exceeds the debt of the vault.
+        RepayExceedsDebt,
+        /// Account already owns the maximum number of vaults.
+        TooManyVaults,
+        /// The vault is above the liquidation ratio.
+        VaultNotLiquidatable,
+        /// No fresh oracle price for the collateral asset.
+        PriceUnavailable,
+        /// The account has not minted that much unredeemed FI against the collateral asset.
+        ExceedsRedeemable,
     }
 
+
     /// The pallet's dispatchable functions.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Mint FI tokens by depositing collateral.
         /// 
-        /// The user must have sufficient collateral balance to mint FI.
-        /// The amount of FI minted will be equal to the collateral amount (1:1 ratio).
+        /// The user must have sufficient collateral balance of `asset_id` to mint FI.
+        /// `amount` of collateral is used and FI is minted 1:1 for it, less the asset's mint fee.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::mint_fi())]
-        pub fn mint_fi(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+        pub fn mint_fi(origin: OriginFor<T>, asset_id: AssetIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
             // Ensure amount is not zero
@@ -144,14 +614,34 @@ pub mod pallet {
             // Ensure amount doesn't exceed maximum
             ensure!(amount <= T::MaxMintAmount::get(), Error::<T>::AmountExceedsMaximum);
 
+            // Ensure the collateral asset accepts new mints
+            let mut asset = CollateralAssets::<T>::get(&asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
+            ensure!(asset.enabled, Error::<T>::CollateralAssetDisabled);
+
             // Check if user has sufficient collateral
-            let user_collateral = Collateral::<T>::get(&who);
+            let user_collateral = Collateral::<T>::get(&who, &asset_id);
             ensure!(user_collateral >= amount, Error::<T>::InsufficientCollateral);
 
+            // All of the collateral backs FI, the fee share of it is minted to the fee engine
+            let fee = asset.mint_fee.mul_ceil(amount);
+            let minted = amount.saturating_sub(fee);
+
+            // Check the reserve cap and debt ceilings of the collateral asset
+            asset.total_issued = asset.total_issued.checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            ensure!(asset.total_issued <= asset.reserve_cap, Error::<T>::ReserveCapExceeded);
+            Self::ensure_within_debt_ceiling(&asset, amount)?;
+
             // Update collateral
             let new_collateral = user_collateral.checked_sub(&amount)
                 .ok_or(Error::<T>::Underflow)?;
-            Collateral::<T>::insert(&who, new_collateral);
+            Collateral::<T>::insert(&who, &asset_id, new_collateral);
+
+            // Update per-asset accounting
+            asset.total_deposited = asset.total_deposited.checked_sub(&amount)
+                .ok_or(Error::<T>::Underflow)?;
+            CollateralAssets::<T>::insert(&asset_id, asset);
 
             // Update total collateral
             let total_collateral = TotalCollateral::<T>::get()
@@ -159,22 +649,26 @@ pub mod pallet {
                 .ok_or(Error::<T>::Underflow)?;
             TotalCollateral::<T>::put(total_collateral);
 
-            // Mint FI tokens
-            let current_balance = Balances::<T>::get(&who);
-            let new_balance = current_balance.checked_add(&amount)
+            // Only the FI minted here can later redeem this asset
+            let redeemable = Redeemable::<T>::get(&who, &asset_id).checked_add(&minted)
                 .ok_or(Error::<T>::Overflow)?;
-            Balances::<T>::insert(&who, new_balance);
+            Redeemable::<T>::insert(&who, &asset_id, redeemable);
 
-            // Update total supply
-            let total_supply = TotalSupply::<T>::get()
-                .checked_add(&amount)
-                .ok_or(Error::<T>::Overflow)?;
-            TotalSupply::<T>::put(total_supply);
+            // Mint FI tokens
+            Self::note_debt_issued(amount)?;
+            Self::mint_fi_internal(&who, minted)?;
+
+            if !fee.is_zero() {
+                Self::mint_fi_internal(&T::FeeEngine::account_id(), fee)?;
+                T::FeeEngine::credit_fee(&who, FeeCategory::PegStability, fee)?;
+            }
 
             Self::deposit_event(Event::Minted {
                 account: who,
-                amount,
+                asset_id,
+                amount: minted,
                 collateral_amount: amount,
+                fee,
             });
 
             Ok(())
@@ -182,47 +676,74 @@ pub mod pallet {
 
         /// Burn FI tokens and return collateral.
         /// 
-        /// The user must have sufficient FI balance to burn.
-        /// The amount of collateral returned will be equal to the FI burned (1:1 ratio).
+        /// The user must have sufficient FI balance to burn, and must have minted at least
+        /// `amount` of FI against `asset_id` that is not redeemed yet.
+        /// Collateral is returned 1:1 for the FI burned, less the asset's redeem fee.
+        ///
+        /// FI can only be redeemed for the asset it was minted against, so FI minted against a
+        /// depegged stablecoin never drains the reserve of another. FI borrowed from a vault is
+        /// repaid through the vault instead.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::burn_fi())]
-        pub fn burn_fi(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+        pub fn burn_fi(origin: OriginFor<T>, asset_id: AssetIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
             // Ensure amount is not zero
             ensure!(!amount.is_zero(), Error::<T>::AmountBelowMinimum);
 
-            // Check if user has sufficient FI balance
-            let current_balance = Balances::<T>::get(&who);
-            ensure!(current_balance >= amount, Error::<T>::InsufficientBalance);
+            let mut asset = CollateralAssets::<T>::get(&asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
 
-            // Burn FI tokens
-            let new_balance = current_balance.checked_sub(&amount)
-                .ok_or(Error::<T>::Underflow)?;
-            Balances::<T>::insert(&who, new_balance);
+            // Only FI minted against this asset redeems it
+            let redeemable = Redeemable::<T>::get(&who, &asset_id).checked_sub(&amount)
+                .ok_or(Error::<T>::ExceedsRedeemable)?;
 
-            // Update total supply
-            let total_supply = TotalSupply::<T>::get()
-                .checked_sub(&amount)
-                .ok_or(Error::<T>::Underflow)?;
-            TotalSupply::<T>::put(total_supply);
+            // The fee is paid to the fee engine in FI, only the rest is redeemed
+            let fee = asset.redeem_fee.mul_ceil(amount);
+            let returned = amount.saturating_sub(fee);
+            ensure!(asset.total_issued >= returned, Error::<T>::InsufficientCollateral);
+
+            // Burn FI tokens and pay the fee
+            Self::burn_fi_internal(&who, amount)?;
+            if redeemable.is_zero() {
+                Redeemable::<T>::remove(&who, &asset_id);
+            } else {
+                Redeemable::<T>::insert(&who, &asset_id, redeemable);
+            }
+            if !fee.is_zero() {
+                Self::mint_fi_internal(&T::FeeEngine::account_id(), fee)?;
+            }
 
             // Return collateral to user's account
-            let user_collateral = Collateral::<T>::get(&who);
-            let new_collateral = user_collateral.checked_add(&amount)
+            let user_collateral = Collateral::<T>::get(&who, &asset_id);
+            let new_collateral = user_collateral.checked_add(&returned)
+                .ok_or(Error::<T>::Overflow)?;
+            Collateral::<T>::insert(&who, &asset_id, new_collateral);
+
+            // Update per-asset accounting
+            asset.total_issued = asset.total_issued.checked_sub(&returned)
+                .ok_or(Error::<T>::Underflow)?;
+            Self::note_debt_repaid(returned)?;
+            asset.total_deposited = asset.total_deposited.checked_add(&returned)
                 .ok_or(Error::<T>::Overflow)?;
-            Collateral::<T>::insert(&who, new_collateral);
+            CollateralAssets::<T>::insert(&asset_id, asset);
 
             // Update total collateral
             let total_collateral = TotalCollateral::<T>::get()
-                .checked_add(&amount)
+                .checked_add(&returned)
                 .ok_or(Error::<T>::Overflow)?;
             TotalCollateral::<T>::put(total_collateral);
 
+            if !fee.is_zero() {
+                T::FeeEngine::credit_fee(&who, FeeCategory::PegStability, fee)?;
+            }
+
             Self::deposit_event(Event::Burned {
                 account: who,
+                asset_id,
                 amount,
-                collateral_returned: amount,
+                collateral_returned: returned,
+                fee,
             });
 
             Ok(())
@@ -230,11 +751,11 @@ pub mod pallet {
 
         /// Deposit collateral into the vault.
         /// 
-        /// This function allows users to deposit stablecoins as collateral.
+        /// This function allows users to deposit a registered stablecoin as collateral.
         /// The collateral can later be used to mint FI tokens.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::deposit_collateral())]
-        pub fn deposit_collateral(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+        pub fn deposit_collateral(origin: OriginFor<T>, asset_id: AssetIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
             // Ensure amount is not zero
@@ -243,19 +764,30 @@ pub mod pallet {
             // Ensure amount meets minimum requirement
             ensure!(amount >= T::MinCollateralAmount::get(), Error::<T>::AmountBelowMinimum);
 
+            // Ensure the collateral asset accepts new deposits
+            let mut asset = CollateralAssets::<T>::get(&asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
+            ensure!(asset.enabled, Error::<T>::CollateralAssetDisabled);
+
             // Transfer collateral from user to vault
-            T::Currency::withdraw(
+            T::Assets::transfer(
+                asset_id.clone(),
                 &who,
+                &Self::account_id(),
                 amount,
-                WithdrawReasons::TRANSFER,
-                ExistenceRequirement::KeepAlive,
+                Preservation::Expendable,
             )?;
 
             // Update user's collateral balance
-            let current_collateral = Collateral::<T>::get(&who);
+            let current_collateral = Collateral::<T>::get(&who, &asset_id);
             let new_collateral = current_collateral.checked_add(&amount)
                 .ok_or(Error::<T>::Overflow)?;
-            Collateral::<T>::insert(&who, new_collateral);
+            Collateral::<T>::insert(&who, &asset_id, new_collateral);
+
+            // Update per-asset accounting
+            asset.total_deposited = asset.total_deposited.checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            CollateralAssets::<T>::insert(&asset_id, asset);
 
             // Update total collateral
             let total_collateral = TotalCollateral::<T>::get()
@@ -265,6 +797,7 @@ pub mod pallet {
 
             Self::deposit_event(Event::CollateralDeposited {
                 account: who,
+                asset_id,
                 amount,
             });
 
@@ -273,23 +806,32 @@ pub mod pallet {
 
         /// Withdraw collateral from the vault.
         /// 
-        /// Users can withdraw their collateral if they have sufficient balance.
+        /// Users can withdraw their collateral if they have sufficient balance. Withdrawals
+        /// remain possible while the collateral asset is disabled.
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::withdraw_collateral())]
-        pub fn withdraw_collateral(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+        pub fn withdraw_collateral(origin: OriginFor<T>, asset_id: AssetIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
             // Ensure amount is not zero
             ensure!(!amount.is_zero(), Error::<T>::AmountBelowMinimum);
 
+            let mut asset = CollateralAssets::<T>::get(&asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
+
             // Check if user has sufficient collateral
-            let current_collateral = Collateral::<T>::get(&who);
+            let current_collateral = Collateral::<T>::get(&who, &asset_id);
             ensure!(current_collateral >= amount, Error::<T>::InsufficientCollateral);
 
             // Update user's collateral balance
             let new_collateral = current_collateral.checked_sub(&amount)
                 .ok_or(Error::<T>::Underflow)?;
-            Collateral::<T>::insert(&who, new_collateral);
+            Collateral::<T>::insert(&who, &asset_id, new_collateral);
+
+            // Update per-asset accounting
+            asset.total_deposited = asset.total_deposited.checked_sub(&amount)
+                .ok_or(Error::<T>::Underflow)?;
+            CollateralAssets::<T>::insert(&asset_id, asset);
 
             // Update total collateral
             let total_collateral = TotalCollateral::<T>::get()
@@ -298,10 +840,514 @@ pub mod pallet {
             TotalCollateral::<T>::put(total_collateral);
 
             // Transfer collateral back to user
-            T::Currency::deposit_creating(&who, amount);
+            T::Assets::transfer(
+                asset_id.clone(),
+                &Self::account_id(),
+                &who,
+                amount,
+                Preservation::Expendable,
+            )?;
 
             Self::deposit_event(Event::CollateralWithdrawn {
                 account: who,
+                asset_id,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Register a stablecoin as an accepted collateral asset.
+        ///
+        /// The asset starts without peg stability fees and with an unlimited reserve cap.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::register_collateral_asset())]
+        pub fn register_collateral_asset(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            symbol: AssetSymbol,
+            debt_ceiling: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::CollateralAdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !CollateralAssets::<T>::contains_key(&asset_id),
+                Error::<T>::CollateralAssetAlreadyRegistered
+            );
+
+            CollateralAssets::<T>::insert(&asset_id, CollateralAssetInfo {
+                symbol: symbol.clone(),
+                enabled: true,
+                debt_ceiling,
+                total_deposited: Zero::zero(),
+                total_issued: Zero::zero(),
+                vault_collateral: Zero::zero(),
+                vault_debt: Zero::zero(),
+                mint_fee: Permill::zero(),
+                redeem_fee: Permill::zero(),
+                reserve_cap: BalanceOf::<T>::max_value(),
+            });
+
+            Self::deposit_event(Event::CollateralAssetRegistered {
+                asset_id,
+                symbol,
+                debt_ceiling,
+            });
+
+            Ok(())
+        }
+
+        /// Enable or disable deposits and minting against a collateral asset.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_collateral_asset_enabled())]
+        pub fn set_collateral_asset_enabled(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::CollateralAdminOrigin::ensure_origin(origin)?;
+
+            CollateralAssets::<T>::try_mutate(&asset_id, |maybe_asset| -> DispatchResult {
+                let asset = maybe_asset.as_mut().ok_or(Error::<T>::CollateralAssetNotFound)?;
+                asset.enabled = enabled;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::CollateralAssetStatusChanged {
+                asset_id,
+                enabled,
+            });
+
+            Ok(())
+        }
+
+        /// Update the maximum amount of FI that may be outstanding against a collateral asset.
+        ///
+        /// Lowering the ceiling below the current issuance only blocks further minting.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_debt_ceiling())]
+        pub fn set_debt_ceiling(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            debt_ceiling: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::CollateralAdminOrigin::ensure_origin(origin)?;
+
+            CollateralAssets::<T>::try_mutate(&asset_id, |maybe_asset| -> DispatchResult {
+                let asset = maybe_asset.as_mut().ok_or(Error::<T>::CollateralAssetNotFound)?;
+                asset.debt_ceiling = debt_ceiling;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::DebtCeilingUpdated {
+                asset_id,
+                debt_ceiling,
+            });
+
+            Ok(())
+        }
+
+        /// Open a vault by locking collateral and optionally drawing FI debt against it.
+        ///
+        /// The vault must satisfy `MinCollateralRatio`. The vault creation fee is charged
+        /// through the fee engine.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::open_vault())]
+        pub fn open_vault(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            collateral: BalanceOf<T>,
+            debt: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Ensure amounts are within limits
+            ensure!(collateral >= T::MinCollateralAmount::get(), Error::<T>::AmountBelowMinimum);
+            ensure!(debt <= T::MaxMintAmount::get(), Error::<T>::AmountExceedsMaximum);
+
+            // Ensure the collateral asset accepts new vaults
+            let mut asset = CollateralAssets::<T>::get(&asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
+            ensure!(asset.enabled, Error::<T>::CollateralAssetDisabled);
+
+            Self::ensure_collateralized(&asset_id, collateral, debt)?;
+
+            // Update per-asset accounting and check the debt ceiling
+            asset.vault_collateral = asset.vault_collateral.checked_add(&collateral)
+                .ok_or(Error::<T>::Overflow)?;
+            asset.vault_debt = asset.vault_debt.checked_add(&debt)
+                .ok_or(Error::<T>::Overflow)?;
+            Self::ensure_within_debt_ceiling(&asset, debt)?;
+
+            let vault_id = NextVaultId::<T>::get();
+            NextVaultId::<T>::put(vault_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+            VaultsByOwner::<T>::try_mutate(&who, |vault_ids| {
+                vault_ids.try_push(vault_id).map_err(|_| Error::<T>::TooManyVaults)
+            })?;
+
+            // Charge the vault creation fee
+            let fee = T::FeeEngine::get_fee(&FeeCategory::VaultCreation)?;
+            T::FeeEngine::collect_fee(&who, FeeCategory::VaultCreation, fee)?;
+
+            // Lock collateral and issue the debt
+            T::Assets::transfer(
+                asset_id.clone(),
+                &who,
+                &Self::account_id(),
+                collateral,
+                Preservation::Expendable,
+            )?;
+            Self::note_debt_issued(debt)?;
+            Self::mint_fi_internal(&who, debt)?;
+
+            CollateralAssets::<T>::insert(&asset_id, asset);
+
+            Vaults::<T>::insert(vault_id, Vault {
+                id: vault_id,
+                owner: who.clone(),
+                collateral_asset: asset_id.clone(),
+                collateral_amount: collateral,
+                debt_amount: debt,
+                created_at: frame_system::Pallet::<T>::block_number(),
+            });
+
+            Self::deposit_event(Event::VaultOpened {
+                vault_id,
+                owner: who,
+                asset_id,
+                collateral,
+                debt,
+            });
+
+            Ok(())
+        }
+
+        /// Add or remove collateral and draw or repay FI debt on a vault.
+        ///
+        /// The vault must satisfy `MinCollateralRatio` after the adjustment.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::adjust_vault())]
+        pub fn adjust_vault(
+            origin: OriginFor<T>,
+            vault_id: VaultId,
+            collateral: Option<Adjustment<BalanceOf<T>>>,
+            debt: Option<Adjustment<BalanceOf<T>>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+            ensure!(vault.owner == who, Error::<T>::NotVaultOwner);
+
+            let asset_id = vault.collateral_asset.clone();
+            let mut asset = CollateralAssets::<T>::get(&asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
+
+            match collateral {
+                Some(Adjustment::Increase(amount)) => {
+                    ensure!(asset.enabled, Error::<T>::CollateralAssetDisabled);
+                    vault.collateral_amount = vault.collateral_amount.checked_add(&amount)
+                        .ok_or(Error::<T>::Overflow)?;
+                    asset.vault_collateral = asset.vault_collateral.checked_add(&amount)
+                        .ok_or(Error::<T>::Overflow)?;
+                    T::Assets::transfer(
+                        asset_id.clone(),
+                        &who,
+                        &Self::account_id(),
+                        amount,
+                        Preservation::Expendable,
+                    )?;
+                },
+                Some(Adjustment::Decrease(amount)) => {
+                    vault.collateral_amount = vault.collateral_amount.checked_sub(&amount)
+                        .ok_or(Error::<T>::InsufficientCollateral)?;
+                    asset.vault_collateral = asset.vault_collateral.checked_sub(&amount)
+                        .ok_or(Error::<T>::Underflow)?;
+                },
+                None => {},
+            }
+
+            match debt {
+                Some(Adjustment::Increase(amount)) => {
+                    ensure!(asset.enabled, Error::<T>::CollateralAssetDisabled);
+                    ensure!(amount <= T::MaxMintAmount::get(), Error::<T>::AmountExceedsMaximum);
+                    vault.debt_amount = vault.debt_amount.checked_add(&amount)
+                        .ok_or(Error::<T>::Overflow)?;
+                    asset.vault_debt = asset.vault_debt.checked_add(&amount)
+                        .ok_or(Error::<T>::Overflow)?;
+                    Self::ensure_within_debt_ceiling(&asset, amount)?;
+                    Self::note_debt_issued(amount)?;
+                    Self::mint_fi_internal(&who, amount)?;
+                },
+                Some(Adjustment::Decrease(amount)) => {
+                    vault.debt_amount = vault.debt_amount.checked_sub(&amount)
+                        .ok_or(Error::<T>::RepayExceedsDebt)?;
+                    asset.vault_debt = asset.vault_debt.checked_sub(&amount)
+                        .ok_or(Error::<T>::Underflow)?;
+                    Self::note_debt_repaid(amount)?;
+                    Self::burn_fi_internal(&who, amount)?;
+                },
+                None => {},
+            }
+
+            Self::ensure_collateralized(&asset_id, vault.collateral_amount, vault.debt_amount)?;
+
+            // Release collateral only once the vault is known to stay healthy
+            if let Some(Adjustment::Decrease(amount)) = collateral {
+                T::Assets::transfer(
+                    asset_id.clone(),
+                    &Self::account_id(),
+                    &who,
+                    amount,
+                    Preservation::Expendable,
+                )?;
+            }
+
+            CollateralAssets::<T>::insert(&asset_id, asset);
+
+            let (collateral, debt) = (vault.collateral_amount, vault.debt_amount);
+            Vaults::<T>::insert(vault_id, vault);
+
+            Self::deposit_event(Event::VaultAdjusted {
+                vault_id,
+                collateral,
+                debt,
+            });
+
+            Ok(())
+        }
+
+        /// Close a vault, repaying its outstanding debt and returning all collateral.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::close_vault())]
+        pub fn close_vault(origin: OriginFor<T>, vault_id: VaultId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+            ensure!(vault.owner == who, Error::<T>::NotVaultOwner);
+
+            let asset_id = vault.collateral_asset.clone();
+            let mut asset = CollateralAssets::<T>::get(&asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
+
+            // Repay the outstanding debt
+            Self::note_debt_repaid(vault.debt_amount)?;
+            Self::burn_fi_internal(&who, vault.debt_amount)?;
+
+            asset.vault_debt = asset.vault_debt.checked_sub(&vault.debt_amount)
+                .ok_or(Error::<T>::Underflow)?;
+            asset.vault_collateral = asset.vault_collateral.checked_sub(&vault.collateral_amount)
+                .ok_or(Error::<T>::Underflow)?;
+            CollateralAssets::<T>::insert(&asset_id, asset);
+
+            // Return the collateral
+            T::Assets::transfer(
+                asset_id,
+                &Self::account_id(),
+                &who,
+                vault.collateral_amount,
+                Preservation::Expendable,
+            )?;
+
+            Vaults::<T>::remove(vault_id);
+            VaultsByOwner::<T>::mutate(&who, |vault_ids| vault_ids.retain(|id| *id != vault_id));
+
+            Self::deposit_event(Event::VaultClosed {
+                vault_id,
+                owner: who,
+                collateral_returned: vault.collateral_amount,
+                debt_repaid: vault.debt_amount,
+            });
+
+            Ok(())
+        }
+
+        /// Liquidate a vault below the liquidation ratio.
+        ///
+        /// The keeper repays the whole debt of the vault in FI and receives collateral worth the
+        /// repaid debt plus `KeeperReward`. A further `LiquidationPenalty` is seized for the
+        /// `LiquidationBeneficiary` and any remaining collateral is returned to the vault owner.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::liquidate_vault())]
+        pub fn liquidate_vault(origin: OriginFor<T>, vault_id: VaultId) -> DispatchResult {
+            let keeper = ensure_signed(origin)?;
+
+            let vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+            ensure!(Self::is_liquidatable(&vault), Error::<T>::VaultNotLiquidatable);
+
+            let asset_id = vault.collateral_asset.clone();
+            let mut asset = CollateralAssets::<T>::get(&asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
+
+            // Repay the debt on behalf of the owner
+            let debt = vault.debt_amount;
+            Self::note_debt_repaid(debt)?;
+            Self::burn_fi_internal(&keeper, debt)?;
+
+            // Split the collateral between keeper, beneficiary and owner
+            let keeper_reward = T::KeeperReward::get() * debt;
+            let penalty = T::LiquidationPenalty::get() * debt;
+            let keeper_value = debt.checked_add(&keeper_reward).ok_or(Error::<T>::Overflow)?;
+
+            let keeper_collateral = Self::collateral_for_value(&asset_id, keeper_value)?
+                .min(vault.collateral_amount);
+            let remaining = vault.collateral_amount.checked_sub(&keeper_collateral)
+                .ok_or(Error::<T>::Underflow)?;
+            let penalty_collateral = Self::collateral_for_value(&asset_id, penalty)?.min(remaining);
+            let owner_collateral = remaining.checked_sub(&penalty_collateral)
+                .ok_or(Error::<T>::Underflow)?;
+
+            asset.vault_debt = asset.vault_debt.checked_sub(&debt)
+                .ok_or(Error::<T>::Underflow)?;
+            asset.vault_collateral = asset.vault_collateral.checked_sub(&vault.collateral_amount)
+                .ok_or(Error::<T>::Underflow)?;
+            CollateralAssets::<T>::insert(&asset_id, asset);
+
+            let pallet_account = Self::account_id();
+            for (recipient, amount) in [
+                (keeper.clone(), keeper_collateral),
+                (T::LiquidationBeneficiary::get(), penalty_collateral),
+                (vault.owner.clone(), owner_collateral),
+            ] {
+                if !amount.is_zero() {
+                    T::Assets::transfer(
+                        asset_id.clone(),
+                        &pallet_account,
+                        &recipient,
+                        amount,
+                        Preservation::Expendable,
+                    )?;
+                }
+            }
+
+            Vaults::<T>::remove(vault_id);
+            VaultsByOwner::<T>::mutate(&vault.owner, |ids| ids.retain(|id| *id != vault_id));
+
+            Self::deposit_event(Event::VaultLiquidated {
+                vault_id,
+                owner: vault.owner,
+                keeper,
+                collateral_seized: keeper_collateral.saturating_add(penalty_collateral),
+                debt_repaid: debt,
+                keeper_reward,
+                penalty,
+            });
+
+            Ok(())
+        }
+
+        /// Set the peg stability fees and reserve cap of a collateral asset.
+        ///
+        /// Lowering the reserve cap below the current reserve only blocks further minting.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_peg_stability_params())]
+        pub fn set_peg_stability_params(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            mint_fee: Permill,
+            redeem_fee: Permill,
+            reserve_cap: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::CollateralAdminOrigin::ensure_origin(origin)?;
+
+            CollateralAssets::<T>::try_mutate(&asset_id, |maybe_asset| -> DispatchResult {
+                let asset = maybe_asset.as_mut().ok_or(Error::<T>::CollateralAssetNotFound)?;
+                asset.mint_fee = mint_fee;
+                asset.redeem_fee = redeem_fee;
+                asset.reserve_cap = reserve_cap;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::PegStabilityParamsUpdated {
+                asset_id,
+                mint_fee,
+                redeem_fee,
+                reserve_cap,
+            });
+
+            Ok(())
+        }
+
+        /// Set or remove the global debt ceiling.
+        ///
+        /// Lowering the ceiling below the current debt only blocks further minting.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_global_debt_ceiling())]
+        pub fn set_global_debt_ceiling(
+            origin: OriginFor<T>,
+            debt_ceiling: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::CollateralAdminOrigin::ensure_origin(origin)?;
+
+            GlobalDebtCeiling::<T>::set(debt_ceiling);
+
+            Self::deposit_event(Event::GlobalDebtCeilingUpdated { debt_ceiling });
+
+            Ok(())
+        }
+
+        /// Pause or resume minting FI against collateral.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_minting_paused())]
+        pub fn set_minting_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            T::CollateralAdminOrigin::ensure_origin(origin)?;
+
+            MintingPaused::<T>::put(paused);
+
+            Self::deposit_event(Event::MintingPauseChanged { paused });
+
+            Ok(())
+        }
+
+        /// Register an account allowed to attest off-chain reserves.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::add_auditor())]
+        pub fn add_auditor(origin: OriginFor<T>, auditor: T::AccountId) -> DispatchResult {
+            T::CollateralAdminOrigin::ensure_origin(origin)?;
+            ensure!(!Auditors::<T>::contains_key(&auditor), Error::<T>::AuditorAlreadyRegistered);
+
+            Auditors::<T>::insert(&auditor, ());
+
+            Self::deposit_event(Event::AuditorAdded { auditor });
+
+            Ok(())
+        }
+
+        /// Remove an auditor. Its past attestations are kept.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::remove_auditor())]
+        pub fn remove_auditor(origin: OriginFor<T>, auditor: T::AccountId) -> DispatchResult {
+            T::CollateralAdminOrigin::ensure_origin(origin)?;
+            ensure!(Auditors::<T>::contains_key(&auditor), Error::<T>::NotAuditor);
+
+            Auditors::<T>::remove(&auditor);
+
+            Self::deposit_event(Event::AuditorRemoved { auditor });
+
+            Ok(())
+        }
+
+        /// Attest the reserves of a backing stablecoin held off-chain, replacing the caller's
+        /// previous attestation for that asset.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::attest_reserves())]
+        pub fn attest_reserves(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let auditor = ensure_signed(origin)?;
+            ensure!(Auditors::<T>::contains_key(&auditor), Error::<T>::NotAuditor);
+            ensure!(CollateralAssets::<T>::contains_key(&asset_id), Error::<T>::CollateralAssetNotFound);
+
+            ReserveAttestations::<T>::insert(&asset_id, &auditor, ReserveAttestation {
+                amount,
+                attested_at: frame_system::Pallet::<T>::block_number(),
+            });
+
+            Self::deposit_event(Event::ReservesAttested {
+                auditor,
+                asset_id,
                 amount,
             });
 
@@ -310,12 +1356,179 @@ pub mod pallet {
     }
 
     impl<T: Config> Pallet<T> {
+        /// The account holding all deposited collateral.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Value of `amount` of a collateral asset, denominated in FI.
+        ///
+        /// Uses the oracle price of the asset and fails without a fresh one.
+        pub fn collateral_value(
+            asset_id: &AssetIdOf<T>,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            Ok(Self::collateral_price(asset_id)?.saturating_mul_int(amount))
+        }
+
+        /// Amount of a collateral asset worth `value` FI.
+        pub fn collateral_for_value(
+            asset_id: &AssetIdOf<T>,
+            value: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let inverse = Self::collateral_price(asset_id)?
+                .reciprocal()
+                .ok_or(Error::<T>::PriceUnavailable)?;
+            Ok(inverse.saturating_mul_int(value))
+        }
+
+        /// Oracle price of one unit of a collateral asset in FI.
+        pub fn collateral_price(asset_id: &AssetIdOf<T>) -> Result<FixedU128, DispatchError> {
+            let asset = CollateralAssets::<T>::get(asset_id)
+                .ok_or(Error::<T>::CollateralAssetNotFound)?;
+            T::PriceProvider::get_price(&asset.symbol).ok_or_else(|| Error::<T>::PriceUnavailable.into())
+        }
+
+        /// Whether a vault is below the liquidation ratio.
+        ///
+        /// Vaults are never liquidatable without a fresh price for their collateral.
+        pub fn is_liquidatable(vault: &Vault<T>) -> bool {
+            if vault.debt_amount.is_zero() {
+                return false;
+            }
+            let threshold = T::LiquidationRatio::get().saturating_mul_int(vault.debt_amount);
+            Self::collateral_value(&vault.collateral_asset, vault.collateral_amount)
+                .is_ok_and(|value| value < threshold)
+        }
+
+        /// Check the next batch of vaults against the liquidation ratio and flag unsafe ones.
+        fn sweep_unsafe_vaults() -> Weight {
+            let next_vault_id = NextVaultId::<T>::get();
+            let checks = (T::MaxLiquidationChecks::get() as u64).min(next_vault_id);
+
+            let mut cursor = LiquidationCursor::<T>::get();
+            for _ in 0..checks {
+                if cursor >= next_vault_id {
+                    cursor = 0;
+                }
+                if let Some(vault) = Vaults::<T>::get(cursor) {
+                    if Self::is_liquidatable(&vault) {
+                        Self::deposit_event(Event::VaultUnsafe {
+                            vault_id: vault.id,
+                            collateral: vault.collateral_amount,
+                            debt: vault.debt_amount,
+                        });
+                    }
+                }
+                cursor = cursor.saturating_add(1);
+            }
+            if checks > 0 {
+                LiquidationCursor::<T>::put(cursor);
+            }
+
+            T::WeightInfo::liquidation_sweep(checks as u32)
+        }
+
+        /// Ensure a position satisfies the minimum collateral ratio.
+        fn ensure_collateralized(
+            asset_id: &AssetIdOf<T>,
+            collateral: BalanceOf<T>,
+            debt: BalanceOf<T>,
+        ) -> DispatchResult {
+            if debt.is_zero() {
+                return Ok(());
+            }
+            let required = T::MinCollateralRatio::get().saturating_mul_int(debt);
+            ensure!(
+                Self::collateral_value(asset_id, collateral)? >= required,
+                Error::<T>::BelowMinCollateralRatio
+            );
+            Ok(())
+        }
+
+        /// Ensure the FI outstanding against an asset, already including `increase`, stays
+        /// within its debt ceiling and the global debt ceiling, and that minting is not paused.
+        ///
+        /// Must be called before the updated asset is written back to storage.
+        fn ensure_within_debt_ceiling(
+            asset: &CollateralAssetInfo<BalanceOf<T>>,
+            increase: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(increase.is_zero() || !MintingPaused::<T>::get(), Error::<T>::MintingPaused);
+
+            let outstanding = asset.total_issued.checked_add(&asset.vault_debt)
+                .ok_or(Error::<T>::Overflow)?;
+            ensure!(outstanding <= asset.debt_ceiling, Error::<T>::DebtCeilingExceeded);
+
+            if let Some(ceiling) = GlobalDebtCeiling::<T>::get() {
+                let total = TotalDebt::<T>::get().checked_add(&increase)
+                    .ok_or(Error::<T>::Overflow)?;
+                ensure!(total <= ceiling, Error::<T>::GlobalDebtCeilingExceeded);
+            }
+            Ok(())
+        }
+
+        /// Add FI issued against collateral to [`TotalDebt`].
+        fn note_debt_issued(amount: BalanceOf<T>) -> DispatchResult {
+            TotalDebt::<T>::try_mutate(|total| -> DispatchResult {
+                *total = total.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+                Ok(())
+            })
+        }
+
+        /// Remove FI repaid against collateral from [`TotalDebt`].
+        fn note_debt_repaid(amount: BalanceOf<T>) -> DispatchResult {
+            TotalDebt::<T>::try_mutate(|total| -> DispatchResult {
+                *total = total.checked_sub(&amount).ok_or(Error::<T>::Underflow)?;
+                Ok(())
+            })
+        }
+
+        /// Check the proof-of-reserves invariants.
+        ///
+        /// The FI supply must not exceed the FI issued against collateral plus the unbacked
+        /// issuance, and the pallet account must hold at least the collateral it accounts for.
+        pub fn check_reserves() -> Result<(), ReserveBreach<AssetIdOf<T>>> {
+            let backed = TotalDebt::<T>::get().saturating_add(UnbackedIssuance::<T>::get());
+            if TotalSupply::<T>::get() > backed {
+                return Err(ReserveBreach::UnbackedSupply);
+            }
+
+            let pallet_account = Self::account_id();
+            for (asset_id, asset) in CollateralAssets::<T>::iter() {
+                let accounted = asset.total_deposited
+                    .saturating_add(asset.total_issued)
+                    .saturating_add(asset.vault_collateral);
+                let held = <T::Assets as fungibles::Inspect<T::AccountId>>::balance(asset_id.clone(), &pallet_account);
+                if held < accounted {
+                    return Err(ReserveBreach::CustodyShortfall(asset_id));
+                }
+            }
+            Ok(())
+        }
+
+        /// Oracle value of all collateral held for FI, divided by the FI supply.
+        ///
+        /// `None` without FI in circulation or without a fresh price for every collateral asset.
+        pub fn collateralization_ratio() -> Option<FixedU128> {
+            let supply = TotalSupply::<T>::get();
+            if supply.is_zero() {
+                return None;
+            }
+            let mut value: BalanceOf<T> = Zero::zero();
+            for (asset_id, asset) in CollateralAssets::<T>::iter() {
+                let reserve = asset.total_issued.saturating_add(asset.vault_collateral);
+                value = value.saturating_add(Self::collateral_value(&asset_id, reserve).ok()?);
+            }
+            Some(FixedU128::saturating_from_rational(value, supply))
+        }
+
         /// Check if an account has sufficient FI balance.
         pub fn has_sufficient_balance(account: &<T as frame_system::Config>::AccountId, amount: BalanceOf<T>) -> bool {
             Balances::<T>::get(account) >= amount
         }
 
-        /// Burn FI tokens from an account (internal function for fee payments).
+        /// Burn FI tokens from an account (internal function for vault debt).
         pub fn burn_fi_internal(account: &<T as frame_system::Config>::AccountId, amount: BalanceOf<T>) -> DispatchResult {
             let current_balance = Balances::<T>::get(account);
             ensure!(current_balance >= amount, Error::<T>::InsufficientBalance);
@@ -333,5 +1546,21 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Mint FI tokens to an account (internal function for vault debt).
+        pub fn mint_fi_internal(account: &<T as frame_system::Config>::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let new_balance = Balances::<T>::get(account)
+                .checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            Balances::<T>::insert(account, new_balance);
+
+            // Update total supply
+            let total_supply = TotalSupply::<T>::get()
+                .checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+            TotalSupply::<T>::put(total_supply);
+
+            Ok(())
+        }
     }
-}
\ No newline at end of file
+}
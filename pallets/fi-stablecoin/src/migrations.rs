@@ -0,0 +1,198 @@
+//! Storage migrations for the FI stablecoin pallet.
+//!
+//! Version 0 is the layout before the collateral asset registry, in which collateral was a plain
+//! amount of the native currency per account.
+
+/// Return the native collateral recorded before the collateral asset registry.
+pub mod v1 {
+    use crate::{BalanceOf, Config, Pallet, TotalCollateral};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        storage_alias,
+        traits::{Currency, UncheckedOnRuntimeUpgrade},
+    };
+    use sp_runtime::traits::Zero;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// `Collateral` as it was stored in version 0: native collateral per account.
+    #[storage_alias]
+    pub type Collateral<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Re-issues the native collateral withdrawn by `deposit_collateral` in version 0 to its
+    /// depositors and clears the old entries, which share their prefix with the per-asset
+    /// `Collateral` map.
+    ///
+    /// FI minted against native collateral stays in circulation; [`crate::migrations::v3`]
+    /// records it as unbacked issuance.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut refunded = 0u64;
+            for (who, amount) in Collateral::<T>::drain() {
+                refunded += 1;
+                if !amount.is_zero() {
+                    let _ = T::Currency::deposit_creating(&who, amount);
+                }
+            }
+            TotalCollateral::<T>::kill();
+            // Each refund reads and writes the depositor's account besides the old entry
+            let accesses = refunded.saturating_mul(2);
+            T::DbWeight::get().reads_writes(accesses, accesses.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(TotalCollateral::<T>::get().is_zero(), "native collateral left after migration");
+            Ok(())
+        }
+    }
+
+    /// [`InnerMigrateV0ToV1`] guarded by the pallet's storage version.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Add the peg stability parameters to every registered collateral asset and start tracking the
+/// total debt.
+pub mod v2 {
+    use crate::{AssetIdOf, AssetSymbol, BalanceOf, CollateralAssetInfo, CollateralAssets, Config, Pallet, TotalDebt};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_runtime::{
+        traits::{Bounded, Saturating, Zero},
+        Permill,
+    };
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// `CollateralAssetInfo` as it was stored in version 1.
+    #[derive(Decode, Encode)]
+    pub struct OldCollateralAssetInfo<Balance> {
+        pub symbol: AssetSymbol,
+        pub enabled: bool,
+        pub debt_ceiling: Balance,
+        pub total_deposited: Balance,
+        pub total_issued: Balance,
+        pub vault_collateral: Balance,
+        pub vault_debt: Balance,
+    }
+
+    /// Registers existing assets without peg stability fees and with an unlimited reserve cap,
+    /// matching the behaviour before the peg stability module, and sums their outstanding FI
+    /// into `TotalDebt`.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            let mut total_debt = BalanceOf::<T>::zero();
+            CollateralAssets::<T>::translate::<OldCollateralAssetInfo<BalanceOf<T>>, _>(
+                |_asset_id: AssetIdOf<T>, old| {
+                    translated += 1;
+                    total_debt = total_debt.saturating_add(old.total_issued).saturating_add(old.vault_debt);
+                    Some(CollateralAssetInfo {
+                        symbol: old.symbol,
+                        enabled: old.enabled,
+                        debt_ceiling: old.debt_ceiling,
+                        total_deposited: old.total_deposited,
+                        total_issued: old.total_issued,
+                        vault_collateral: old.vault_collateral,
+                        vault_debt: old.vault_debt,
+                        mint_fee: Permill::zero(),
+                        redeem_fee: Permill::zero(),
+                        reserve_cap: BalanceOf::<T>::max_value(),
+                    })
+                },
+            );
+            TotalDebt::<T>::put(total_debt);
+            T::DbWeight::get().reads_writes(translated, translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((CollateralAssets::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+            ensure!(
+                CollateralAssets::<T>::iter_values().count() as u32 == count,
+                "collateral assets lost during migration"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`InnerMigrateV1ToV2`] guarded by the pallet's storage version.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Record the FI issued without collateral before proof-of-reserves tracking.
+pub mod v3 {
+    use crate::{Config, Pallet, TotalSupply, UnbackedIssuance};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_runtime::traits::Saturating;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Sets [`UnbackedIssuance`] to the part of the FI supply not issued against collateral,
+    /// so existing genesis balances do not break the reserve invariant.
+    pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            UnbackedIssuance::<T>::put(TotalSupply::<T>::get().saturating_sub(Pallet::<T>::total_debt()));
+            T::DbWeight::get().reads_writes(2, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                TotalSupply::<T>::get() <= Pallet::<T>::total_debt().saturating_add(UnbackedIssuance::<T>::get()),
+                "FI supply not covered after migration"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`InnerMigrateV2ToV3`] guarded by the pallet's storage version.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -0,0 +1,234 @@
+use crate::{self as pallet_fi_stablecoin, payment::FiCurrencyAdapter, AssetSymbol};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Contains},
+    weights::IdentityFee,
+    PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_fee_engine::FeeCategory;
+use scale_info::TypeInfo;
+use sp_runtime::{BuildStorage, FixedU128, Permill, RuntimeDebug};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type TransactionPayment = pallet_transaction_payment::Pallet<Test>;
+
+    #[runtime::pallet_index(3)]
+    pub type Assets = pallet_assets::Pallet<Test>;
+
+    #[runtime::pallet_index(4)]
+    pub type Oracle = pallet_oracle::Pallet<Test>;
+
+    #[runtime::pallet_index(5)]
+    pub type FeeEngine = pallet_fee_engine::Pallet<Test>;
+
+    #[runtime::pallet_index(6)]
+    pub type FiStablecoin = pallet_fi_stablecoin::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+#[derive_impl(pallet_transaction_payment::config_preludes::TestDefaultConfig)]
+impl pallet_transaction_payment::Config for Test {
+    type OnChargeTransaction = FiCurrencyAdapter<Test, BlockAuthor>;
+    type WeightToFee = IdentityFee<u64>;
+    type LengthToFee = IdentityFee<u64>;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+    type ForceOrigin = EnsureRoot<u64>;
+}
+
+impl pallet_oracle::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type OracleKey = AssetSymbol;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type MaxFeeders = ConstU32<4>;
+    type MinimumFeeds = ConstU32<1>;
+    type MaxPriceAge = ConstU64<MAX_PRICE_AGE>;
+}
+
+parameter_types! {
+    pub const FeeEnginePalletId: PalletId = PalletId(*b"cfi/fees");
+    pub const FiStablecoinPalletId: PalletId = PalletId(*b"cfi/fist");
+    pub MinCollateralRatio: FixedU128 = FixedU128::from_rational(150, 100);
+    pub LiquidationRatio: FixedU128 = FixedU128::from_rational(110, 100);
+    // Keeper reward and penalty stay below the liquidation ratio so that owners keep a remainder.
+    pub const LiquidationPenalty: Permill = Permill::from_percent(2);
+    pub const KeeperReward: Permill = Permill::from_percent(3);
+    pub const BlockAuthor: Option<u64> = Some(AUTHOR);
+}
+
+impl pallet_fee_engine::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = FiStablecoin;
+    type WeightInfo = ();
+    type FounderAccount = ConstU64<FOUNDER>;
+    type DaoTreasuryAccount = ConstU64<DAO>;
+    type CallToFeeType = ();
+    type PalletId = FeeEnginePalletId;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type MaxFeeScheduleLen = ConstU32<4>;
+    type MinAnnouncementPeriod = ConstU64<10>;
+    type MaxFeeBeneficiaries = ConstU32<4>;
+    type StakeProvider = ();
+    type MaxDiscountTiers = ConstU32<3>;
+    type RevenueEraLength = ConstU64<100>;
+    type RevenueRetention = ConstU32<2>;
+    type DaoTreasury = ();
+    type SweepPeriod = ConstU64<100>;
+}
+
+pub struct MockSystemAccounts;
+
+impl Contains<u64> for MockSystemAccounts {
+    fn contains(account: &u64) -> bool {
+        *account == FeeEngine::account_id()
+    }
+}
+
+// None of the pallets in the mock hold FI, so the tests bring their own hold reasons.
+#[derive(Clone, Copy, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TestHoldReason {
+    Escrow,
+    Deposit,
+}
+
+impl pallet_fi_stablecoin::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Assets = Assets;
+    type CollateralAdminOrigin = EnsureRoot<u64>;
+    type PalletId = FiStablecoinPalletId;
+    type FeeEngine = FeeEngine;
+    type MinCollateralRatio = MinCollateralRatio;
+    type MaxVaultsPerAccount = ConstU32<2>;
+    type PriceProvider = Oracle;
+    type LiquidationRatio = LiquidationRatio;
+    type LiquidationPenalty = LiquidationPenalty;
+    type KeeperReward = KeeperReward;
+    type LiquidationBeneficiary = ConstU64<BENEFICIARY>;
+    type MaxLiquidationChecks = ConstU32<2>;
+    type WeightInfo = pallet_fi_stablecoin::weights::SubstrateWeight<Test>;
+    type MinCollateralAmount = ConstU64<MIN_COLLATERAL>;
+    type MaxMintAmount = ConstU64<MAX_MINT>;
+    type SystemAccounts = MockSystemAccounts;
+    type RuntimeHoldReason = TestHoldReason;
+}
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const KEEPER: u64 = 3;
+pub const FEEDER: u64 = 5;
+// Holds neither FI nor collateral.
+pub const CHARLIE: u64 = 6;
+pub const FOUNDER: u64 = 100;
+pub const DAO: u64 = 101;
+pub const BENEFICIARY: u64 = 102;
+pub const ASSET_ADMIN: u64 = 103;
+// Receives transaction tips.
+pub const AUTHOR: u64 = 104;
+
+pub const USDT: u32 = 1;
+pub const DAI: u32 = 2;
+
+pub const MIN_COLLATERAL: u64 = 10;
+pub const MAX_MINT: u64 = 1_000_000;
+pub const MAX_PRICE_AGE: u64 = 10;
+pub const DEBT_CEILING: u64 = 1_000_000;
+pub const VAULT_FEE: u64 = 5;
+
+// FI and collateral every user account starts with.
+pub const INITIAL_FI: u64 = 1_000;
+pub const INITIAL_COLLATERAL: u64 = 100_000;
+
+pub fn symbol(ticker: &[u8]) -> AssetSymbol {
+    ticker.to_vec().try_into().unwrap()
+}
+
+// Publish the oracle price of a collateral asset in FI.
+pub fn set_price(ticker: &[u8], price: FixedU128) {
+    Oracle::feed_price(RuntimeOrigin::signed(FEEDER), symbol(ticker), price).unwrap();
+}
+
+// Build genesis storage with USDT and DAI registered as collateral at par, `ALICE`, `BOB` and
+// `KEEPER` holding FI and both stablecoins, and the gas and vault creation fees set.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let users = [ALICE, BOB, KEEPER];
+    pallet_assets::GenesisConfig::<Test> {
+        assets: vec![(USDT, ASSET_ADMIN, true, 1), (DAI, ASSET_ADMIN, true, 1)],
+        accounts: users
+            .iter()
+            .flat_map(|who| [(USDT, *who, INITIAL_COLLATERAL), (DAI, *who, INITIAL_COLLATERAL)])
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    pallet_fi_stablecoin::GenesisConfig::<Test> {
+        balances: users.iter().map(|who| (*who, INITIAL_FI)).collect(),
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    pallet_fee_engine::GenesisConfig::<Test> {
+        fees: vec![(FeeCategory::GasFee, 1), (FeeCategory::VaultCreation, VAULT_FEE)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        for (asset_id, ticker) in [(USDT, &b"USDT"[..]), (DAI, &b"DAI"[..])] {
+            FiStablecoin::register_collateral_asset(RuntimeOrigin::root(), asset_id, symbol(ticker), DEBT_CEILING)
+                .unwrap();
+        }
+        Oracle::add_feeder(RuntimeOrigin::root(), FEEDER).unwrap();
+        set_price(b"USDT", FixedU128::from_u32(1));
+        set_price(b"DAI", FixedU128::from_u32(1));
+    });
+    ext
+}
@@ -0,0 +1,129 @@
+//! Transaction fee payment in FI.
+//!
+//! [`FiCurrencyAdapter`] plugs into `pallet_transaction_payment` so that gas is paid from the FI
+//! ledger of this pallet instead of the native balance. The estimated fee is transferred to the
+//! fee engine account before dispatch, the part covering unused weight is refunded afterwards and
+//! the final fee is credited to the fee engine's beneficiaries as a `FeeCategory::GasFee` payment.
+//!
+//! The tip is paid to the account given by `TipRecipient`, usually the block author. Without one,
+//! or if paying it fails, the tip is credited to the beneficiaries with the rest of the fee.
+//!
+//! Failures after dispatch cannot reject the transaction, so they are logged and the fee engine
+//! account keeps what it could not refund or credit.
+//!
+//! The fee engine account must be one of [`Config::SystemAccounts`].
+
+use crate::{BalanceOf, Config, Pallet};
+use core::marker::PhantomData;
+use frame_support::traits::{fungible::Mutate, tokens::Preservation, Get};
+use pallet_fee_engine::{FeeCategory, FeeEngineInterface};
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+    traits::{DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+const LOG_TARGET: &str = "runtime::fi-stablecoin";
+
+/// Charges transaction fees in FI, paying tips to `TipRecipient`.
+pub struct FiCurrencyAdapter<T, TipRecipient = ()>(PhantomData<(T, TipRecipient)>);
+
+impl<T, TipRecipient> OnChargeTransaction<T> for FiCurrencyAdapter<T, TipRecipient>
+where
+    T: Config + pallet_transaction_payment::Config,
+    TipRecipient: Get<Option<T::AccountId>>,
+{
+    type Balance = BalanceOf<T>;
+    /// The FI withdrawn before dispatch, if any.
+    type LiquidityInfo = Option<BalanceOf<T>>;
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        _call: &T::RuntimeCall,
+        _dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        fee: Self::Balance,
+        _tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        if fee.is_zero() {
+            return Ok(None);
+        }
+
+        let fee_account = <T as Config>::FeeEngine::account_id();
+        <Pallet<T> as Mutate<T::AccountId>>::transfer(who, &fee_account, fee, Preservation::Expendable)
+            .map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+
+        Ok(Some(fee))
+    }
+
+    fn can_withdraw_fee(
+        who: &T::AccountId,
+        _call: &T::RuntimeCall,
+        _dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        fee: Self::Balance,
+        _tip: Self::Balance,
+    ) -> Result<(), TransactionValidityError> {
+        if fee.is_zero() || Pallet::<T>::has_sufficient_balance(who, fee) {
+            Ok(())
+        } else {
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+        }
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        _dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        _post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        corrected_fee: Self::Balance,
+        tip: Self::Balance,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let Some(paid) = already_withdrawn else {
+            return Ok(());
+        };
+        let fee_account = <T as Config>::FeeEngine::account_id();
+
+        // Refund the fee for unused weight, keeping it if the refund fails
+        let mut refund = paid.saturating_sub(corrected_fee);
+        if !refund.is_zero() {
+            let refunded =
+                <Pallet<T> as Mutate<T::AccountId>>::transfer(&fee_account, who, refund, Preservation::Expendable);
+            if let Err(error) = refunded {
+                log::error!(target: LOG_TARGET, "Failed to refund {:?} of gas to {:?}: {:?}", refund, who, error);
+                refund = Zero::zero();
+            }
+        }
+        let mut fee = paid.saturating_sub(refund);
+
+        // Pay the tip out of the fee, if anyone is to receive it
+        let tip = tip.min(fee);
+        if let Some(recipient) = TipRecipient::get().filter(|_| !tip.is_zero()) {
+            let tipped =
+                <Pallet<T> as Mutate<T::AccountId>>::transfer(&fee_account, &recipient, tip, Preservation::Expendable);
+            match tipped {
+                Ok(_) => fee = fee.saturating_sub(tip),
+                Err(error) => {
+                    log::error!(target: LOG_TARGET, "Failed to pay a tip of {:?} to {:?}: {:?}", tip, recipient, error)
+                }
+            }
+        }
+
+        // The rest already sits in the fee engine account
+        if !fee.is_zero() {
+            if let Err(error) = <T as Config>::FeeEngine::credit_fee(who, FeeCategory::GasFee, fee) {
+                log::error!(target: LOG_TARGET, "Failed to credit a gas fee of {:?} from {:?}: {}", fee, who, error);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn endow_account(who: &T::AccountId, amount: Self::Balance) {
+        let _ = Pallet::<T>::mint_fi_internal(who, amount);
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn minimum_balance() -> Self::Balance {
+        Zero::zero()
+    }
+}
@@ -0,0 +1,1197 @@
+use crate::{
+    migrations, mock::*, payment::FiCurrencyAdapter, Adjustment, CollateralAssets, Error, Event, ReserveAttestation,
+    ReserveBreach, TotalCollateral, TotalSupply,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    storage::unhashed,
+    traits::{
+        fungible::{
+            hold::{Inspect as HoldInspect, Mutate as HoldMutate, Unbalanced as HoldUnbalanced},
+            Inspect, Mutate, Unbalanced,
+        },
+        tokens::{Fortitude, Precision, Preservation, Restriction},
+        Hooks, OnRuntimeUpgrade, StorageVersion,
+    },
+    weights::Weight,
+};
+use pallet_fee_engine::{Event as FeeEngineEvent, FeeCategory};
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+    DispatchError, FixedU128, Permill, TokenError,
+};
+
+// Asset created in `pallet_assets` but not registered as collateral.
+const USDC: u32 = 3;
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+#[test]
+fn gas_is_paid_into_the_fee_engine_account() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo::default();
+        let supply = FiStablecoin::total_supply();
+
+        let withdrawn = FiCurrencyAdapter::<Test>::withdraw_fee(&ALICE, &remark(), &info, 100, 0).unwrap();
+        assert_eq!(withdrawn, Some(100));
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - 100);
+        assert_eq!(FiStablecoin::balance_of(FeeEngine::account_id()), 100);
+        // Gas moves FI around but never burns it
+        assert_eq!(FiStablecoin::total_supply(), supply);
+    });
+}
+
+#[test]
+fn unused_gas_is_refunded_and_the_rest_is_split() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo::default();
+        let withdrawn = FiCurrencyAdapter::<Test>::withdraw_fee(&ALICE, &remark(), &info, 100, 0).unwrap();
+
+        assert_ok!(FiCurrencyAdapter::<Test>::correct_and_deposit_fee(
+            &ALICE,
+            &info,
+            &PostDispatchInfo::default(),
+            60,
+            0,
+            withdrawn,
+        ));
+
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - 60);
+        assert_eq!(FiStablecoin::balance_of(FeeEngine::account_id()), 60);
+        System::assert_last_event(
+            FeeEngineEvent::FeeCollected { payer: ALICE, category: FeeCategory::GasFee, amount: 60, discount: 0 }
+                .into(),
+        );
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER), 9);
+        assert_eq!(FeeEngine::accrued_fees(DAO), 51);
+
+        // Beneficiaries withdraw their share of the gas in FI
+        assert_ok!(FeeEngine::withdraw_fees(RuntimeOrigin::signed(DAO)));
+        assert_eq!(FiStablecoin::balance_of(DAO), 51);
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+    });
+}
+
+// The adapter of the mock runtime, paying tips to `AUTHOR`.
+type TipPaying = FiCurrencyAdapter<Test, BlockAuthor>;
+
+#[test]
+fn tips_are_paid_to_the_tip_recipient() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo::default();
+        let withdrawn = TipPaying::withdraw_fee(&ALICE, &remark(), &info, 100, 10).unwrap();
+
+        assert_ok!(TipPaying::correct_and_deposit_fee(
+            &ALICE,
+            &info,
+            &PostDispatchInfo::default(),
+            60,
+            10,
+            withdrawn,
+        ));
+
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - 60);
+        assert_eq!(FiStablecoin::balance_of(AUTHOR), 10);
+        assert_eq!(FiStablecoin::balance_of(FeeEngine::account_id()), 50);
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER) + FeeEngine::accrued_fees(DAO), 50);
+    });
+}
+
+#[test]
+fn tips_without_a_recipient_are_split_with_the_fee() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo::default();
+        let withdrawn = FiCurrencyAdapter::<Test>::withdraw_fee(&ALICE, &remark(), &info, 100, 10).unwrap();
+
+        assert_ok!(FiCurrencyAdapter::<Test>::correct_and_deposit_fee(
+            &ALICE,
+            &info,
+            &PostDispatchInfo::default(),
+            60,
+            10,
+            withdrawn,
+        ));
+
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - 60);
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER) + FeeEngine::accrued_fees(DAO), 60);
+    });
+}
+
+#[test]
+fn failed_refunds_keep_the_fee() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo::default();
+        let withdrawn = FiCurrencyAdapter::<Test>::withdraw_fee(&ALICE, &remark(), &info, 100, 0).unwrap();
+        // Empty the fee engine account so that the refund cannot be paid
+        assert_ok!(<FiStablecoin as Mutate<_>>::transfer(
+            &FeeEngine::account_id(),
+            &BOB,
+            100,
+            Preservation::Expendable
+        ));
+
+        // The transaction is not rejected after dispatch; the whole fee is kept
+        assert_ok!(FiCurrencyAdapter::<Test>::correct_and_deposit_fee(
+            &ALICE,
+            &info,
+            &PostDispatchInfo::default(),
+            60,
+            0,
+            withdrawn,
+        ));
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - 100);
+        System::assert_last_event(
+            FeeEngineEvent::FeeCollected { payer: ALICE, category: FeeCategory::GasFee, amount: 100, discount: 0 }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn gas_cannot_be_paid_without_enough_fi() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo::default();
+        let payment_error = TransactionValidityError::Invalid(InvalidTransaction::Payment);
+
+        assert_eq!(
+            FiCurrencyAdapter::<Test>::can_withdraw_fee(&CHARLIE, &remark(), &info, 1, 0),
+            Err(payment_error)
+        );
+        assert_eq!(
+            FiCurrencyAdapter::<Test>::withdraw_fee(&ALICE, &remark(), &info, INITIAL_FI + 1, 0),
+            Err(payment_error)
+        );
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI);
+        assert_eq!(FiStablecoin::balance_of(FeeEngine::account_id()), 0);
+
+        // A zero fee never needs FI
+        assert_ok!(FiCurrencyAdapter::<Test>::can_withdraw_fee(&CHARLIE, &remark(), &info, 0, 0));
+        assert_eq!(FiCurrencyAdapter::<Test>::withdraw_fee(&CHARLIE, &remark(), &info, 0, 0), Ok(None));
+    });
+}
+
+#[test]
+fn collateral_assets_are_registered_once_by_the_admin() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), USDC, ASSET_ADMIN, true, 1));
+        assert_noop!(
+            FiStablecoin::register_collateral_asset(RuntimeOrigin::signed(ALICE), USDC, symbol(b"USDC"), 100),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(FiStablecoin::register_collateral_asset(RuntimeOrigin::root(), USDC, symbol(b"USDC"), 100));
+        System::assert_last_event(
+            Event::CollateralAssetRegistered { asset_id: USDC, symbol: symbol(b"USDC"), debt_ceiling: 100 }.into(),
+        );
+        let asset = FiStablecoin::collateral_asset(USDC).unwrap();
+        assert!(asset.enabled);
+        assert_eq!(asset.debt_ceiling, 100);
+        assert_eq!(asset.total_issued, 0);
+
+        assert_noop!(
+            FiStablecoin::register_collateral_asset(RuntimeOrigin::root(), USDT, symbol(b"USDT"), 100),
+            Error::<Test>::CollateralAssetAlreadyRegistered
+        );
+    });
+}
+
+#[test]
+fn unregistered_assets_cannot_be_deposited() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDC, 100),
+            Error::<Test>::CollateralAssetNotFound
+        );
+        assert_noop!(
+            FiStablecoin::set_collateral_asset_enabled(RuntimeOrigin::root(), USDC, false),
+            Error::<Test>::CollateralAssetNotFound
+        );
+    });
+}
+
+#[test]
+fn collateral_is_tracked_per_account_and_asset() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 300));
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), DAI, 200));
+        assert_eq!(FiStablecoin::collateral_of(ALICE, USDT), 300);
+        assert_eq!(FiStablecoin::collateral_of(ALICE, DAI), 200);
+        assert_eq!(FiStablecoin::total_collateral(), 500);
+
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI + 100);
+        assert_eq!(FiStablecoin::collateral_of(ALICE, USDT), 200);
+        assert_eq!(FiStablecoin::collateral_asset(USDT).unwrap().total_issued, 100);
+        assert_eq!(FiStablecoin::collateral_asset(DAI).unwrap().total_issued, 0);
+
+        // Alice minted nothing against DAI, so she cannot redeem it
+        assert_noop!(
+            FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), DAI, 50),
+            Error::<Test>::ExceedsRedeemable
+        );
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 50));
+        assert_eq!(FiStablecoin::collateral_of(ALICE, USDT), 250);
+        assert_eq!(FiStablecoin::collateral_asset(USDT).unwrap().total_issued, 50);
+    });
+}
+
+#[test]
+fn fi_only_redeems_the_asset_it_was_minted_against() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 100));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(BOB), DAI, 100));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(BOB), DAI, 100));
+        assert_eq!(FiStablecoin::redeemable_of(ALICE, USDT), 100);
+
+        // Alice minted against USDT and cannot redeem the DAI Bob minted against
+        assert_noop!(
+            FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), DAI, 60),
+            Error::<Test>::ExceedsRedeemable
+        );
+
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 60));
+        assert_eq!(FiStablecoin::redeemable_of(ALICE, USDT), 40);
+        assert_eq!(FiStablecoin::collateral_asset(USDT).unwrap().total_issued, 40);
+        assert_eq!(FiStablecoin::collateral_asset(DAI).unwrap().total_issued, 100);
+
+        // FI she holds from elsewhere does not count, only what she minted
+        assert!(FiStablecoin::balance_of(ALICE) > 41);
+        assert_noop!(
+            FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 41),
+            Error::<Test>::ExceedsRedeemable
+        );
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 40));
+        assert!(!crate::Redeemable::<Test>::contains_key(ALICE, USDT));
+    });
+}
+
+#[test]
+fn minting_is_bounded_by_the_asset_debt_ceiling() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::set_debt_ceiling(RuntimeOrigin::root(), USDT, 150));
+        System::assert_last_event(Event::DebtCeilingUpdated { asset_id: USDT, debt_ceiling: 150 }.into());
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 300));
+
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+        assert_noop!(
+            FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 51),
+            Error::<Test>::DebtCeilingExceeded
+        );
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 50));
+
+        // Lowering the ceiling below the issuance only blocks new mints
+        assert_ok!(FiStablecoin::set_debt_ceiling(RuntimeOrigin::root(), USDT, 100));
+        assert_noop!(
+            FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 10),
+            Error::<Test>::DebtCeilingExceeded
+        );
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+        assert_eq!(FiStablecoin::collateral_asset(USDT).unwrap().total_issued, 50);
+    });
+}
+
+#[test]
+fn disabled_assets_can_only_be_withdrawn() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 200));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+
+        assert_noop!(
+            FiStablecoin::set_collateral_asset_enabled(RuntimeOrigin::signed(ALICE), USDT, false),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(FiStablecoin::set_collateral_asset_enabled(RuntimeOrigin::root(), USDT, false));
+        System::assert_last_event(Event::CollateralAssetStatusChanged { asset_id: USDT, enabled: false }.into());
+
+        assert_noop!(
+            FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 100),
+            Error::<Test>::CollateralAssetDisabled
+        );
+        assert_noop!(
+            FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 50),
+            Error::<Test>::CollateralAssetDisabled
+        );
+
+        // Holders can still redeem and take their collateral home
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+        assert_ok!(FiStablecoin::withdraw_collateral(RuntimeOrigin::signed(ALICE), USDT, 200));
+        assert_eq!(Assets::balance(USDT, ALICE), INITIAL_COLLATERAL);
+
+        assert_ok!(FiStablecoin::set_collateral_asset_enabled(RuntimeOrigin::root(), USDT, true));
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 100));
+    });
+}
+
+#[test]
+fn native_collateral_is_returned_by_the_registry_migration() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<FiStablecoin>();
+        migrations::v1::Collateral::<Test>::insert(CHARLIE, 70);
+        migrations::v1::Collateral::<Test>::insert(ALICE, 30);
+        TotalCollateral::<Test>::put(100);
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Balances::free_balance(CHARLIE), 70);
+        assert_eq!(Balances::free_balance(ALICE), 30);
+        assert_eq!(migrations::v1::Collateral::<Test>::iter().count(), 0);
+        assert_eq!(FiStablecoin::total_collateral(), 0);
+        assert_eq!(StorageVersion::get::<FiStablecoin>(), 1);
+
+        // Running it again is a no-op
+        migrations::v1::Collateral::<Test>::insert(CHARLIE, 5);
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Balances::free_balance(CHARLIE), 70);
+    });
+}
+
+fn set_psm_fees(asset_id: u32, mint_fee: u32, redeem_fee: u32, reserve_cap: u64) {
+    assert_ok!(FiStablecoin::set_peg_stability_params(
+        RuntimeOrigin::root(),
+        asset_id,
+        Permill::from_percent(mint_fee),
+        Permill::from_percent(redeem_fee),
+        reserve_cap,
+    ));
+}
+
+#[test]
+fn mint_fees_are_paid_to_the_fee_engine() {
+    new_test_ext().execute_with(|| {
+        set_psm_fees(USDT, 1, 0, u64::MAX);
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 1_000));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 1_000));
+
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI + 990);
+        assert_eq!(FiStablecoin::balance_of(FeeEngine::account_id()), 10);
+        // The whole deposit backs FI, including the fee
+        assert_eq!(FiStablecoin::collateral_asset(USDT).unwrap().total_issued, 1_000);
+        assert_eq!(FiStablecoin::total_debt(), 1_000);
+        System::assert_has_event(
+            FeeEngineEvent::FeeCollected { payer: ALICE, category: FeeCategory::PegStability, amount: 10, discount: 0 }
+                .into(),
+        );
+
+        // Beneficiaries can take the fee out of the fee engine
+        assert_eq!(FeeEngine::accrued_fees(FOUNDER) + FeeEngine::accrued_fees(DAO), 10);
+        assert_ok!(FeeEngine::withdraw_fees(RuntimeOrigin::signed(DAO)));
+        assert_eq!(FiStablecoin::balance_of(DAO), 10 - FeeEngine::accrued_fees(FOUNDER));
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+    });
+}
+
+#[test]
+fn redeem_fees_are_paid_to_the_fee_engine() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 1_000));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 1_000));
+        set_psm_fees(USDT, 0, 2, u64::MAX);
+
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 500));
+        System::assert_has_event(
+            Event::Burned { account: ALICE, asset_id: USDT, amount: 500, collateral_returned: 490, fee: 10 }.into(),
+        );
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI + 500);
+        assert_eq!(FiStablecoin::balance_of(FeeEngine::account_id()), 10);
+        assert_eq!(FiStablecoin::collateral_of(ALICE, USDT), 490);
+        // The fee stays in circulation, still backed by the collateral it was not redeemed for
+        assert_eq!(FiStablecoin::collateral_asset(USDT).unwrap().total_issued, 510);
+        assert_eq!(FiStablecoin::total_debt(), 510);
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+    });
+}
+
+#[test]
+fn minting_is_bounded_by_the_reserve_cap() {
+    new_test_ext().execute_with(|| {
+        set_psm_fees(USDT, 1, 0, 500);
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 1_000));
+
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 400));
+        // The fee counts towards the reserve as well
+        assert_noop!(
+            FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 101),
+            Error::<Test>::ReserveCapExceeded
+        );
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+    });
+}
+
+#[test]
+fn a_depegged_asset_cannot_drain_the_reserve_of_another() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 1_000));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 1_000));
+
+        // DAI depegs and Bob mints as much FI against cheap DAI as its reserve cap allows
+        set_price(b"DAI", FixedU128::from_rational(1, 2));
+        set_psm_fees(DAI, 0, 0, 2_000);
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(BOB), DAI, 5_000));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(BOB), DAI, 2_000));
+        assert_noop!(
+            FiStablecoin::mint_fi(RuntimeOrigin::signed(BOB), DAI, 1),
+            Error::<Test>::ReserveCapExceeded
+        );
+
+        // His FI cannot be swapped for the USDT backing Alice's
+        assert_noop!(
+            FiStablecoin::burn_fi(RuntimeOrigin::signed(BOB), USDT, 1),
+            Error::<Test>::ExceedsRedeemable
+        );
+
+        // So the USDT reserve survives in full for Alice, and Bob only gets his DAI back
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 1_000));
+        assert_eq!(FiStablecoin::collateral_of(ALICE, USDT), 1_000);
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(BOB), DAI, 2_000));
+        assert_eq!(FiStablecoin::collateral_of(BOB, DAI), 5_000);
+        assert_eq!(FiStablecoin::collateral_asset(USDT).unwrap().total_issued, 0);
+        assert_eq!(FiStablecoin::collateral_asset(DAI).unwrap().total_issued, 0);
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+    });
+}
+
+#[test]
+fn minting_is_bounded_by_the_global_debt_ceiling() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::set_global_debt_ceiling(RuntimeOrigin::root(), Some(300)));
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 300));
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), DAI, 300));
+
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 200));
+        assert_noop!(
+            FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), DAI, 101),
+            Error::<Test>::GlobalDebtCeilingExceeded
+        );
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), DAI, 100));
+
+        // Redeeming frees room under the ceiling
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 50));
+        assert_eq!(FiStablecoin::total_debt(), 250);
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), DAI, 50));
+
+        assert_ok!(FiStablecoin::set_global_debt_ceiling(RuntimeOrigin::root(), None));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), DAI, 100));
+        assert_eq!(FiStablecoin::total_debt(), 400);
+    });
+}
+
+#[test]
+fn peg_stability_migration_keeps_assets_and_sums_their_debt() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<FiStablecoin>();
+        for (asset_id, ticker, issued, vault_debt) in [(USDT, &b"USDT"[..], 300, 50), (DAI, &b"DAI"[..], 100, 0)] {
+            let old = migrations::v2::OldCollateralAssetInfo {
+                symbol: symbol(ticker),
+                enabled: asset_id == USDT,
+                debt_ceiling: DEBT_CEILING,
+                total_deposited: 20u64,
+                total_issued: issued,
+                vault_collateral: 2 * vault_debt,
+                vault_debt,
+            };
+            unhashed::put(&CollateralAssets::<Test>::hashed_key_for(asset_id), &old);
+        }
+
+        migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        let usdt = FiStablecoin::collateral_asset(USDT).unwrap();
+        assert!(usdt.enabled);
+        assert_eq!((usdt.total_deposited, usdt.total_issued, usdt.vault_collateral, usdt.vault_debt), (20, 300, 100, 50));
+        assert_eq!((usdt.mint_fee, usdt.redeem_fee, usdt.reserve_cap), (Permill::zero(), Permill::zero(), u64::MAX));
+        assert!(!FiStablecoin::collateral_asset(DAI).unwrap().enabled);
+        assert_eq!(FiStablecoin::total_debt(), 450);
+        assert_eq!(StorageVersion::get::<FiStablecoin>(), 2);
+    });
+}
+
+fn open_vault(who: u64, collateral: u64, debt: u64) -> u64 {
+    let vault_id = crate::NextVaultId::<Test>::get();
+    assert_ok!(FiStablecoin::open_vault(RuntimeOrigin::signed(who), USDT, collateral, debt));
+    vault_id
+}
+
+#[test]
+fn vaults_open_at_the_minimum_collateral_ratio() {
+    new_test_ext().execute_with(|| {
+        // 150 USDT back at most 100 FI at 150%
+        assert_noop!(
+            FiStablecoin::open_vault(RuntimeOrigin::signed(ALICE), USDT, 150, 101),
+            Error::<Test>::BelowMinCollateralRatio
+        );
+        assert_noop!(
+            FiStablecoin::open_vault(RuntimeOrigin::signed(ALICE), USDT, MIN_COLLATERAL - 1, 0),
+            Error::<Test>::AmountBelowMinimum
+        );
+
+        let vault_id = open_vault(ALICE, 150, 100);
+        System::assert_last_event(
+            Event::VaultOpened { vault_id, owner: ALICE, asset_id: USDT, collateral: 150, debt: 100 }.into(),
+        );
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI + 100 - VAULT_FEE);
+        assert_eq!(FiStablecoin::balance_of(FeeEngine::account_id()), VAULT_FEE);
+        assert_eq!(Assets::balance(USDT, ALICE), INITIAL_COLLATERAL - 150);
+        assert_eq!(FiStablecoin::vaults_of(ALICE).into_inner(), vec![vault_id]);
+
+        let asset = FiStablecoin::collateral_asset(USDT).unwrap();
+        assert_eq!((asset.vault_collateral, asset.vault_debt), (150, 100));
+        assert_eq!(FiStablecoin::total_debt(), 100);
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+    });
+}
+
+#[test]
+fn vaults_per_account_are_limited() {
+    new_test_ext().execute_with(|| {
+        open_vault(ALICE, 100, 0);
+        open_vault(ALICE, 100, 0);
+        assert_noop!(
+            FiStablecoin::open_vault(RuntimeOrigin::signed(ALICE), USDT, 100, 0),
+            Error::<Test>::TooManyVaults
+        );
+        open_vault(BOB, 100, 0);
+    });
+}
+
+#[test]
+fn vaults_cannot_be_opened_against_disabled_assets() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::set_collateral_asset_enabled(RuntimeOrigin::root(), USDT, false));
+        assert_noop!(
+            FiStablecoin::open_vault(RuntimeOrigin::signed(ALICE), USDT, 150, 100),
+            Error::<Test>::CollateralAssetDisabled
+        );
+    });
+}
+
+#[test]
+fn vault_adjustments_keep_the_minimum_collateral_ratio() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 300, 100);
+
+        assert_noop!(
+            FiStablecoin::adjust_vault(RuntimeOrigin::signed(BOB), vault_id, None, Some(Adjustment::Increase(1))),
+            Error::<Test>::NotVaultOwner
+        );
+        assert_noop!(
+            FiStablecoin::adjust_vault(RuntimeOrigin::signed(ALICE), vault_id, None, Some(Adjustment::Increase(101))),
+            Error::<Test>::BelowMinCollateralRatio
+        );
+        assert_noop!(
+            FiStablecoin::adjust_vault(
+                RuntimeOrigin::signed(ALICE),
+                vault_id,
+                Some(Adjustment::Decrease(151)),
+                None
+            ),
+            Error::<Test>::BelowMinCollateralRatio
+        );
+        assert_noop!(
+            FiStablecoin::adjust_vault(RuntimeOrigin::signed(ALICE), vault_id, None, Some(Adjustment::Decrease(101))),
+            Error::<Test>::RepayExceedsDebt
+        );
+
+        // Drawing more debt needs more collateral in the same adjustment
+        assert_ok!(FiStablecoin::adjust_vault(
+            RuntimeOrigin::signed(ALICE),
+            vault_id,
+            Some(Adjustment::Increase(150)),
+            Some(Adjustment::Increase(200)),
+        ));
+        System::assert_last_event(Event::VaultAdjusted { vault_id, collateral: 450, debt: 300 }.into());
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI + 300 - VAULT_FEE);
+        assert_eq!(FiStablecoin::total_debt(), 300);
+
+        // Repaying debt frees collateral
+        assert_ok!(FiStablecoin::adjust_vault(
+            RuntimeOrigin::signed(ALICE),
+            vault_id,
+            Some(Adjustment::Decrease(300)),
+            Some(Adjustment::Decrease(200)),
+        ));
+        let vault = FiStablecoin::vaults(vault_id).unwrap();
+        assert_eq!((vault.collateral_amount, vault.debt_amount), (150, 100));
+        assert_eq!(Assets::balance(USDT, ALICE), INITIAL_COLLATERAL - 150);
+        assert_eq!(FiStablecoin::total_debt(), 100);
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+    });
+}
+
+#[test]
+fn closing_a_vault_repays_its_debt_and_returns_the_collateral() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 300, 100);
+
+        assert_noop!(
+            FiStablecoin::close_vault(RuntimeOrigin::signed(BOB), vault_id),
+            Error::<Test>::NotVaultOwner
+        );
+        assert_ok!(FiStablecoin::close_vault(RuntimeOrigin::signed(ALICE), vault_id));
+        System::assert_last_event(
+            Event::VaultClosed { vault_id, owner: ALICE, collateral_returned: 300, debt_repaid: 100 }.into(),
+        );
+
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - VAULT_FEE);
+        assert_eq!(Assets::balance(USDT, ALICE), INITIAL_COLLATERAL);
+        assert!(FiStablecoin::vaults(vault_id).is_none());
+        assert!(FiStablecoin::vaults_of(ALICE).is_empty());
+        assert_eq!(FiStablecoin::total_debt(), 0);
+        assert_noop!(
+            FiStablecoin::close_vault(RuntimeOrigin::signed(ALICE), vault_id),
+            Error::<Test>::VaultNotFound
+        );
+    });
+}
+
+#[test]
+fn vaults_cannot_be_closed_without_the_fi_to_repay() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 300, 100);
+        // Spend FI on gas so that less than the debt is left
+        assert_ok!(FiCurrencyAdapter::<Test>::withdraw_fee(&ALICE, &remark(), &DispatchInfo::default(), INITIAL_FI, 0));
+        assert_eq!(FiStablecoin::balance_of(ALICE), 100 - VAULT_FEE);
+
+        assert_noop!(
+            FiStablecoin::close_vault(RuntimeOrigin::signed(ALICE), vault_id),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+fn unsafe_vaults() -> Vec<u64> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::FiStablecoin(Event::VaultUnsafe { vault_id, .. }) => Some(vault_id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn vaults_become_liquidatable_below_the_liquidation_ratio() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 200, 100);
+        assert_noop!(
+            FiStablecoin::liquidate_vault(RuntimeOrigin::signed(KEEPER), vault_id),
+            Error::<Test>::VaultNotLiquidatable
+        );
+
+        // 200 USDT at 0.55 are worth 110 FI, exactly the liquidation ratio
+        set_price(b"USDT", FixedU128::from_rational(55, 100));
+        assert!(!FiStablecoin::is_liquidatable(&FiStablecoin::vaults(vault_id).unwrap()));
+
+        set_price(b"USDT", FixedU128::from_rational(54, 100));
+        assert!(FiStablecoin::is_liquidatable(&FiStablecoin::vaults(vault_id).unwrap()));
+    });
+}
+
+#[test]
+fn liquidation_splits_the_collateral_between_keeper_beneficiary_and_owner() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 200, 100);
+        // The collateral is now worth 108 FI
+        set_price(b"USDT", FixedU128::from_rational(54, 100));
+
+        assert_ok!(FiStablecoin::liquidate_vault(RuntimeOrigin::signed(KEEPER), vault_id));
+        System::assert_last_event(
+            Event::VaultLiquidated {
+                vault_id,
+                owner: ALICE,
+                keeper: KEEPER,
+                collateral_seized: 193,
+                debt_repaid: 100,
+                keeper_reward: 3,
+                penalty: 2,
+            }
+            .into(),
+        );
+
+        // The keeper repays the debt and receives collateral worth 103 FI
+        assert_eq!(FiStablecoin::balance_of(KEEPER), INITIAL_FI - 100);
+        assert_eq!(Assets::balance(USDT, KEEPER), INITIAL_COLLATERAL + 190);
+        // The penalty of 2 FI goes to the beneficiary and the owner keeps the rest
+        assert_eq!(Assets::balance(USDT, BENEFICIARY), 3);
+        assert_eq!(Assets::balance(USDT, ALICE), INITIAL_COLLATERAL - 200 + 7);
+
+        assert!(FiStablecoin::vaults(vault_id).is_none());
+        assert!(FiStablecoin::vaults_of(ALICE).is_empty());
+        let asset = FiStablecoin::collateral_asset(USDT).unwrap();
+        assert_eq!((asset.vault_collateral, asset.vault_debt), (0, 0));
+        assert_eq!(FiStablecoin::total_debt(), 0);
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+    });
+}
+
+#[test]
+fn liquidation_caps_the_seized_collateral_at_the_vault_collateral() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 200, 100);
+        // The collateral is worth 80 FI, less than the debt
+        set_price(b"USDT", FixedU128::from_rational(40, 100));
+
+        assert_ok!(FiStablecoin::liquidate_vault(RuntimeOrigin::signed(KEEPER), vault_id));
+        assert_eq!(Assets::balance(USDT, KEEPER), INITIAL_COLLATERAL + 200);
+        assert_eq!(Assets::balance(USDT, BENEFICIARY), 0);
+        assert_eq!(Assets::balance(USDT, ALICE), INITIAL_COLLATERAL - 200);
+    });
+}
+
+#[test]
+fn liquidation_needs_the_fi_to_repay_the_debt() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 200, 100);
+        set_price(b"USDT", FixedU128::from_rational(54, 100));
+
+        assert_noop!(
+            FiStablecoin::liquidate_vault(RuntimeOrigin::signed(CHARLIE), vault_id),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn sweep_flags_unsafe_vaults_in_batches() {
+    new_test_ext().execute_with(|| {
+        let safe = open_vault(ALICE, 1_000, 100);
+        let first = open_vault(ALICE, 200, 100);
+        let second = open_vault(BOB, 200, 100);
+        set_price(b"USDT", FixedU128::from_rational(54, 100));
+        System::reset_events();
+
+        // At most `MaxLiquidationChecks` vaults are checked per block
+        FiStablecoin::on_initialize(2);
+        assert_eq!(unsafe_vaults(), vec![first]);
+        assert_eq!(crate::LiquidationCursor::<Test>::get(), 2);
+
+        // The sweep resumes where it stopped and wraps around
+        System::reset_events();
+        FiStablecoin::on_initialize(3);
+        assert_eq!(unsafe_vaults(), vec![second]);
+
+        // Flagged vaults stay open until a keeper liquidates them
+        assert!(FiStablecoin::vaults(first).is_some());
+        assert!(FiStablecoin::vaults(safe).is_some());
+    });
+}
+
+#[test]
+fn collateral_is_valued_at_the_oracle_price() {
+    new_test_ext().execute_with(|| {
+        set_price(b"USDT", FixedU128::from_rational(80, 100));
+        assert_eq!(FiStablecoin::collateral_value(&USDT, 1_000), Ok(800));
+        assert_eq!(FiStablecoin::collateral_for_value(&USDT, 800), Ok(1_000));
+
+        // A depegged collateral asset backs less debt
+        assert_noop!(
+            FiStablecoin::open_vault(RuntimeOrigin::signed(ALICE), USDT, 150, 81),
+            Error::<Test>::BelowMinCollateralRatio
+        );
+        open_vault(ALICE, 150, 80);
+    });
+}
+
+#[test]
+fn vault_operations_need_a_fresh_price() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 200, 100);
+
+        // Prices older than `MaxPriceAge` blocks are not used
+        System::set_block_number(2 + MAX_PRICE_AGE);
+        assert_eq!(FiStablecoin::collateral_price(&USDT), Err(Error::<Test>::PriceUnavailable.into()));
+        assert_noop!(
+            FiStablecoin::open_vault(RuntimeOrigin::signed(ALICE), USDT, 200, 100),
+            Error::<Test>::PriceUnavailable
+        );
+        assert_noop!(
+            FiStablecoin::adjust_vault(RuntimeOrigin::signed(ALICE), vault_id, None, Some(Adjustment::Increase(1))),
+            Error::<Test>::PriceUnavailable
+        );
+
+        // Debt-free vaults and closing do not depend on the price
+        open_vault(ALICE, 200, 0);
+        assert_ok!(FiStablecoin::close_vault(RuntimeOrigin::signed(ALICE), vault_id));
+    });
+}
+
+#[test]
+fn vaults_are_not_liquidated_without_a_fresh_price() {
+    new_test_ext().execute_with(|| {
+        let vault_id = open_vault(ALICE, 200, 100);
+        set_price(b"USDT", FixedU128::from_rational(54, 100));
+
+        System::set_block_number(2 + MAX_PRICE_AGE);
+        System::reset_events();
+        FiStablecoin::on_initialize(2 + MAX_PRICE_AGE);
+        assert!(unsafe_vaults().is_empty());
+        assert_noop!(
+            FiStablecoin::liquidate_vault(RuntimeOrigin::signed(KEEPER), vault_id),
+            Error::<Test>::VaultNotLiquidatable
+        );
+
+        // A fresh price makes the vault liquidatable again
+        set_price(b"USDT", FixedU128::from_rational(54, 100));
+        assert_ok!(FiStablecoin::liquidate_vault(RuntimeOrigin::signed(KEEPER), vault_id));
+    });
+}
+
+#[test]
+fn assets_without_a_price_cannot_back_vaults() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), USDC, ASSET_ADMIN, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ASSET_ADMIN), USDC, ALICE, 1_000));
+        assert_ok!(FiStablecoin::register_collateral_asset(RuntimeOrigin::root(), USDC, symbol(b"USDC"), 1_000));
+
+        assert_noop!(
+            FiStablecoin::open_vault(RuntimeOrigin::signed(ALICE), USDC, 200, 100),
+            Error::<Test>::PriceUnavailable
+        );
+        // The 1:1 mint path does not use the oracle
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDC, 100));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDC, 100));
+        assert_eq!(FiStablecoin::collateralization_ratio(), None);
+    });
+}
+
+const AUDITOR: u64 = 104;
+
+fn run_reserve_check() {
+    FiStablecoin::on_idle(System::block_number(), Weight::MAX);
+}
+
+#[test]
+fn reserves_cover_minted_fi_and_vault_debt() {
+    new_test_ext().execute_with(|| {
+        // Genesis balances are recorded as unbacked issuance
+        assert_eq!(FiStablecoin::unbacked_issuance(), 3 * INITIAL_FI);
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 500));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 300));
+        open_vault(BOB, 300, 100);
+        assert_ok!(FiStablecoin::burn_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+        assert_eq!(FiStablecoin::check_reserves(), Ok(()));
+
+        run_reserve_check();
+        assert!(!FiStablecoin::minting_paused());
+    });
+}
+
+#[test]
+fn unbacked_supply_pauses_minting() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 500));
+        TotalSupply::<Test>::mutate(|supply| *supply += 1);
+        assert_eq!(FiStablecoin::check_reserves(), Err(ReserveBreach::UnbackedSupply));
+
+        run_reserve_check();
+        System::assert_has_event(Event::ReserveInvariantBroken { breach: ReserveBreach::UnbackedSupply }.into());
+        System::assert_last_event(Event::MintingPauseChanged { paused: true }.into());
+        assert!(FiStablecoin::minting_paused());
+
+        assert_noop!(
+            FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 100),
+            Error::<Test>::MintingPaused
+        );
+        assert_noop!(
+            FiStablecoin::open_vault(RuntimeOrigin::signed(ALICE), USDT, 300, 100),
+            Error::<Test>::MintingPaused
+        );
+        // Holders can still leave while minting is paused
+        open_vault(ALICE, 300, 0);
+        assert_ok!(FiStablecoin::withdraw_collateral(RuntimeOrigin::signed(ALICE), USDT, 500));
+    });
+}
+
+#[test]
+fn custody_shortfall_pauses_minting_until_resumed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 500));
+        // Collateral leaves the pallet account behind its back
+        assert_ok!(Assets::burn(RuntimeOrigin::signed(ASSET_ADMIN), USDT, FiStablecoin::account_id(), 1));
+        assert_eq!(FiStablecoin::check_reserves(), Err(ReserveBreach::CustodyShortfall(USDT)));
+
+        run_reserve_check();
+        System::assert_has_event(
+            Event::ReserveInvariantBroken { breach: ReserveBreach::CustodyShortfall(USDT) }.into(),
+        );
+        assert!(FiStablecoin::minting_paused());
+
+        // A paused pallet is not checked again
+        System::reset_events();
+        run_reserve_check();
+        assert!(System::events().is_empty());
+
+        assert_noop!(FiStablecoin::set_minting_paused(RuntimeOrigin::signed(ALICE), false), DispatchError::BadOrigin);
+        assert_ok!(FiStablecoin::set_minting_paused(RuntimeOrigin::root(), false));
+        System::assert_last_event(Event::MintingPauseChanged { paused: false }.into());
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 100));
+    });
+}
+
+#[test]
+fn reserve_check_waits_for_enough_idle_weight() {
+    new_test_ext().execute_with(|| {
+        TotalSupply::<Test>::mutate(|supply| *supply += 1);
+
+        FiStablecoin::on_idle(System::block_number(), Weight::zero());
+        assert!(!FiStablecoin::minting_paused());
+
+        run_reserve_check();
+        assert!(FiStablecoin::minting_paused());
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_state_checks_the_reserves() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::try_state(System::block_number()));
+
+        TotalSupply::<Test>::mutate(|supply| *supply += 1);
+        assert!(FiStablecoin::try_state(System::block_number()).is_err());
+    });
+}
+
+#[test]
+fn auditors_are_managed_by_the_admin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(FiStablecoin::add_auditor(RuntimeOrigin::signed(ALICE), AUDITOR), DispatchError::BadOrigin);
+        assert_ok!(FiStablecoin::add_auditor(RuntimeOrigin::root(), AUDITOR));
+        System::assert_last_event(Event::AuditorAdded { auditor: AUDITOR }.into());
+        assert_noop!(
+            FiStablecoin::add_auditor(RuntimeOrigin::root(), AUDITOR),
+            Error::<Test>::AuditorAlreadyRegistered
+        );
+
+        assert_ok!(FiStablecoin::remove_auditor(RuntimeOrigin::root(), AUDITOR));
+        System::assert_last_event(Event::AuditorRemoved { auditor: AUDITOR }.into());
+        assert_noop!(FiStablecoin::remove_auditor(RuntimeOrigin::root(), AUDITOR), Error::<Test>::NotAuditor);
+    });
+}
+
+#[test]
+fn auditors_attest_off_chain_reserves() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FiStablecoin::attest_reserves(RuntimeOrigin::signed(AUDITOR), USDT, 1_000),
+            Error::<Test>::NotAuditor
+        );
+        assert_ok!(FiStablecoin::add_auditor(RuntimeOrigin::root(), AUDITOR));
+        assert_noop!(
+            FiStablecoin::attest_reserves(RuntimeOrigin::signed(AUDITOR), USDC, 1_000),
+            Error::<Test>::CollateralAssetNotFound
+        );
+
+        assert_ok!(FiStablecoin::attest_reserves(RuntimeOrigin::signed(AUDITOR), USDT, 1_000));
+        System::assert_last_event(Event::ReservesAttested { auditor: AUDITOR, asset_id: USDT, amount: 1_000 }.into());
+        assert_eq!(
+            FiStablecoin::reserve_attestation(USDT, AUDITOR),
+            Some(ReserveAttestation { amount: 1_000, attested_at: 1 })
+        );
+
+        // A new attestation replaces the previous one
+        System::set_block_number(5);
+        assert_ok!(FiStablecoin::attest_reserves(RuntimeOrigin::signed(AUDITOR), USDT, 1_200));
+        assert_eq!(
+            FiStablecoin::reserve_attestation(USDT, AUDITOR),
+            Some(ReserveAttestation { amount: 1_200, attested_at: 5 })
+        );
+
+        // Removed auditors keep their history but cannot attest again
+        assert_ok!(FiStablecoin::remove_auditor(RuntimeOrigin::root(), AUDITOR));
+        assert!(FiStablecoin::reserve_attestation(USDT, AUDITOR).is_some());
+        assert_noop!(
+            FiStablecoin::attest_reserves(RuntimeOrigin::signed(AUDITOR), USDT, 1_300),
+            Error::<Test>::NotAuditor
+        );
+    });
+}
+
+#[test]
+fn collateralization_ratio_values_reserves_at_oracle_prices() {
+    new_test_ext().execute_with(|| {
+        // Only unbacked genesis FI is in circulation
+        assert_eq!(FiStablecoin::collateralization_ratio(), Some(FixedU128::from_u32(0)));
+
+        assert_ok!(FiStablecoin::deposit_collateral(RuntimeOrigin::signed(ALICE), USDT, 3_000));
+        assert_ok!(FiStablecoin::mint_fi(RuntimeOrigin::signed(ALICE), USDT, 3_000));
+        assert_eq!(FiStablecoin::collateralization_ratio(), Some(FixedU128::from_rational(1, 2)));
+
+        // Vault collateral counts towards the reserves
+        open_vault(BOB, 3_000, 0);
+        assert_eq!(FiStablecoin::collateralization_ratio(), Some(FixedU128::from_u32(1)));
+
+        set_price(b"USDT", FixedU128::from_rational(80, 100));
+        assert_eq!(FiStablecoin::collateralization_ratio(), Some(FixedU128::from_rational(4, 5)));
+
+        // Without a fresh price for every asset the ratio is unknown
+        System::set_block_number(2 + MAX_PRICE_AGE);
+        assert_eq!(FiStablecoin::collateralization_ratio(), None);
+    });
+}
+
+#[test]
+fn collateralization_ratio_is_unknown_without_supply() {
+    new_test_ext().execute_with(|| {
+        for who in [ALICE, BOB, KEEPER] {
+            crate::Balances::<Test>::remove(who);
+        }
+        TotalSupply::<Test>::kill();
+        assert_eq!(FiStablecoin::collateralization_ratio(), None);
+    });
+}
+
+#[test]
+fn fungible_transfers_need_a_system_account() {
+    new_test_ext().execute_with(|| {
+        let fee_account = FeeEngine::account_id();
+        assert_noop!(
+            <FiStablecoin as Mutate<_>>::transfer(&ALICE, &BOB, 1, Preservation::Expendable),
+            TokenError::Blocked
+        );
+
+        assert_ok!(<FiStablecoin as Mutate<_>>::transfer(&ALICE, &fee_account, 100, Preservation::Expendable));
+        System::assert_last_event(Event::Transferred { from: ALICE, to: fee_account, amount: 100 }.into());
+        assert_ok!(<FiStablecoin as Mutate<_>>::transfer(&fee_account, &BOB, 40, Preservation::Expendable));
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - 100);
+        assert_eq!(FiStablecoin::balance_of(BOB), INITIAL_FI + 40);
+        assert_noop!(
+            <FiStablecoin as Mutate<_>>::transfer(&fee_account, &BOB, 61, Preservation::Expendable),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn fungible_traits_cannot_change_the_fi_supply() {
+    new_test_ext().execute_with(|| {
+        let supply = FiStablecoin::total_supply();
+
+        assert_noop!(<FiStablecoin as Mutate<_>>::mint_into(&ALICE, 1), TokenError::Unsupported);
+        assert_noop!(
+            <FiStablecoin as Mutate<_>>::burn_from(
+                &ALICE,
+                1,
+                Preservation::Expendable,
+                Precision::Exact,
+                Fortitude::Force
+            ),
+            TokenError::Unsupported
+        );
+        assert_noop!(FiStablecoin::write_balance(&ALICE, 0), TokenError::Unsupported);
+        assert_noop!(
+            <FiStablecoin as Unbalanced<_>>::increase_balance(&ALICE, 1, Precision::Exact),
+            TokenError::Unsupported
+        );
+
+        FiStablecoin::set_total_issuance(supply + 1_000);
+        assert_eq!(FiStablecoin::total_supply(), supply);
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI);
+    });
+}
+
+#[test]
+fn held_fi_stays_in_the_total_balance_until_released() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FiStablecoin::hold(&TestHoldReason::Escrow, &ALICE, 300));
+        assert_ok!(FiStablecoin::hold(&TestHoldReason::Deposit, &ALICE, 200));
+        System::assert_last_event(Event::Held { reason: TestHoldReason::Deposit, account: ALICE, amount: 200 }.into());
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - 500);
+        assert_eq!(FiStablecoin::balance_on_hold(&TestHoldReason::Escrow, &ALICE), 300);
+        assert_eq!(FiStablecoin::total_balance_on_hold(&ALICE), 500);
+        assert_eq!(<FiStablecoin as Inspect<_>>::total_balance(&ALICE), INITIAL_FI);
+        assert_noop!(
+            FiStablecoin::hold(&TestHoldReason::Escrow, &ALICE, INITIAL_FI - 499),
+            TokenError::FundsUnavailable
+        );
+
+        assert_ok!(FiStablecoin::release(&TestHoldReason::Escrow, &ALICE, 1_000, Precision::BestEffort));
+        System::assert_last_event(Event::Released { reason: TestHoldReason::Escrow, account: ALICE, amount: 300 }.into());
+        assert_eq!(FiStablecoin::balance_of(ALICE), INITIAL_FI - 200);
+        assert_eq!(FiStablecoin::total_balance_on_hold(&ALICE), 200);
+        assert_noop!(
+            FiStablecoin::release(&TestHoldReason::Deposit, &ALICE, 201, Precision::Exact),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn held_fi_only_moves_to_or_from_system_accounts_and_cannot_be_burned() {
+    new_test_ext().execute_with(|| {
+        let fee_account = FeeEngine::account_id();
+        let supply = FiStablecoin::total_supply();
+        assert_ok!(FiStablecoin::hold(&TestHoldReason::Escrow, &ALICE, 500));
+
+        assert_noop!(
+            FiStablecoin::transfer_on_hold(
+                &TestHoldReason::Escrow,
+                &ALICE,
+                &BOB,
+                100,
+                Precision::Exact,
+                Restriction::Free,
+                Fortitude::Polite,
+            ),
+            TokenError::Blocked
+        );
+        assert_noop!(
+            FiStablecoin::burn_held(&TestHoldReason::Escrow, &ALICE, 100, Precision::Exact, Fortitude::Force),
+            TokenError::Unsupported
+        );
+        assert_noop!(
+            FiStablecoin::set_balance_on_hold(&TestHoldReason::Escrow, &ALICE, 0),
+            TokenError::Unsupported
+        );
+
+        assert_ok!(FiStablecoin::transfer_on_hold(
+            &TestHoldReason::Escrow,
+            &ALICE,
+            &fee_account,
+            100,
+            Precision::Exact,
+            Restriction::Free,
+            Fortitude::Polite,
+        ));
+        System::assert_last_event(
+            Event::HeldTransferred { reason: TestHoldReason::Escrow, from: ALICE, to: fee_account, amount: 100 }
+                .into(),
+        );
+        assert_eq!(FiStablecoin::balance_of(fee_account), 100);
+
+        assert_ok!(FiStablecoin::transfer_on_hold(
+            &TestHoldReason::Escrow,
+            &ALICE,
+            &fee_account,
+            150,
+            Precision::Exact,
+            Restriction::OnHold,
+            Fortitude::Polite,
+        ));
+        assert_eq!(FiStablecoin::balance_on_hold(&TestHoldReason::Escrow, &fee_account), 150);
+        assert_eq!(FiStablecoin::total_balance_on_hold(&ALICE), 250);
+
+        assert_ok!(FiStablecoin::transfer_and_hold(
+            &TestHoldReason::Deposit,
+            &BOB,
+            &fee_account,
+            50,
+            Precision::Exact,
+            Preservation::Expendable,
+            Fortitude::Polite,
+        ));
+        System::assert_last_event(
+            Event::Held { reason: TestHoldReason::Deposit, account: fee_account, amount: 50 }.into(),
+        );
+        assert_eq!(FiStablecoin::balance_of(BOB), INITIAL_FI - 50);
+        assert_eq!(FiStablecoin::total_balance_on_hold(&fee_account), 200);
+
+        // Holding moves FI around but never changes the supply
+        assert_eq!(FiStablecoin::total_supply(), supply);
+    });
+}
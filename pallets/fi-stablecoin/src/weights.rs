@@ -41,79 +41,390 @@ pub trait WeightInfo {
 	fn burn_fi() -> Weight;
 	fn deposit_collateral() -> Weight;
 	fn withdraw_collateral() -> Weight;
+	fn register_collateral_asset() -> Weight;
+	fn set_collateral_asset_enabled() -> Weight;
+	fn set_debt_ceiling() -> Weight;
+	fn open_vault() -> Weight;
+	fn adjust_vault() -> Weight;
+	fn close_vault() -> Weight;
+	fn liquidate_vault() -> Weight;
+	fn liquidation_sweep(n: u32, ) -> Weight;
+	fn set_peg_stability_params() -> Weight;
+	fn set_global_debt_ceiling() -> Weight;
+	fn reserve_check() -> Weight;
+	fn set_minting_paused() -> Weight;
+	fn add_auditor() -> Weight;
+	fn remove_auditor() -> Weight;
+	fn attest_reserves() -> Weight;
 }
 
 /// Weights for `pallet_fi_stablecoin` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: `FiStablecoin::Balances` (r:1 w:1)
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Balances` (r:2 w:2)
 	/// Proof: `FiStablecoin::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::Collateral` (r:1 w:1)
-	/// Proof: `FiStablecoin::Collateral` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `FiStablecoin::Collateral` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Redeemable` (r:1 w:1)
+	/// Proof: `FiStablecoin::Redeemable` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::TotalSupply` (r:1 w:1)
 	/// Proof: `FiStablecoin::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::TotalCollateral` (r:1 w:1)
 	/// Proof: `FiStablecoin::TotalCollateral` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::MintingPaused` (r:1 w:0)
+	/// Proof: `FiStablecoin::MintingPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::GlobalDebtCeiling` (r:1 w:0)
+	/// Proof: `FiStablecoin::GlobalDebtCeiling` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalDebt` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalDebt` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::TotalFeesCollected` (r:1 w:1)
+	/// Proof: `FeeEngine::TotalFeesCollected` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::CurrentRevenueEra` (r:1 w:0)
+	/// Proof: `FeeEngine::CurrentRevenueEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::EraRevenue` (r:1 w:1)
+	/// Proof: `FeeEngine::EraRevenue` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::FeeSplit` (r:1 w:0)
+	/// Proof: `FeeEngine::FeeSplit` (`max_values`: Some(1), `max_size`: Some(289), added: 784, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::AccruedFees` (r:2 w:2)
+	/// Proof: `FeeEngine::AccruedFees` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn mint_fi() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `6047`
-		//  Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(25_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 6047))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+		//  Estimated: `13691`
+		//  Minimum execution time: 57_000_000 picoseconds.
+		Weight::from_parts(57_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 13691))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(12))
 	}
-	/// Storage: `FiStablecoin::Balances` (r:1 w:1)
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Balances` (r:2 w:2)
 	/// Proof: `FiStablecoin::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::TotalSupply` (r:1 w:1)
 	/// Proof: `FiStablecoin::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::Collateral` (r:1 w:1)
-	/// Proof: `FiStablecoin::Collateral` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `FiStablecoin::Collateral` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Redeemable` (r:1 w:1)
+	/// Proof: `FiStablecoin::Redeemable` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::TotalCollateral` (r:1 w:1)
 	/// Proof: `FiStablecoin::TotalCollateral` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Accounts` (r:1 w:1)
-	/// Proof: `Balances::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalDebt` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalDebt` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::TotalFeesCollected` (r:1 w:1)
+	/// Proof: `FeeEngine::TotalFeesCollected` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::CurrentRevenueEra` (r:1 w:0)
+	/// Proof: `FeeEngine::CurrentRevenueEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::EraRevenue` (r:1 w:1)
+	/// Proof: `FeeEngine::EraRevenue` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::FeeSplit` (r:1 w:0)
+	/// Proof: `FeeEngine::FeeSplit` (`max_values`: Some(1), `max_size`: Some(289), added: 784, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::AccruedFees` (r:2 w:2)
+	/// Proof: `FeeEngine::AccruedFees` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn burn_fi() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `8667`
-		//  Minimum execution time: 35_000_000 picoseconds.
-		Weight::from_parts(35_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 8667))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-	}
-	/// Storage: `Balances::Accounts` (r:1 w:1)
-	/// Proof: `Balances::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+		//  Estimated: `13750`
+		//  Minimum execution time: 60_000_000 picoseconds.
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 13750))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::Collateral` (r:1 w:1)
-	/// Proof: `FiStablecoin::Collateral` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `FiStablecoin::Collateral` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::TotalCollateral` (r:1 w:1)
 	/// Proof: `FiStablecoin::TotalCollateral` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn deposit_collateral() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `5638`
-		//  Minimum execution time: 30_000_000 picoseconds.
-		Weight::from_parts(30_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 5638))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Estimated: `6208`
+		//  Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::Collateral` (r:1 w:1)
-	/// Proof: `FiStablecoin::Collateral` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `FiStablecoin::Collateral` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `FiStablecoin::TotalCollateral` (r:1 w:1)
 	/// Proof: `FiStablecoin::TotalCollateral` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Accounts` (r:1 w:1)
-	/// Proof: `Balances::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	fn withdraw_collateral() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `5638`
-		//  Minimum execution time: 30_000_000 picoseconds.
-		Weight::from_parts(30_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 5638))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Estimated: `6208`
+		//  Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	fn register_collateral_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3551`
+		//  Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	fn set_collateral_asset_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3551`
+		//  Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	fn set_debt_ceiling() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3551`
+		//  Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::NextVaultId` (r:1 w:1)
+	/// Proof: `FiStablecoin::NextVaultId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::VaultsByOwner` (r:1 w:1)
+	/// Proof: `FiStablecoin::VaultsByOwner` (`max_values`: None, `max_size`: Some(849), added: 3324, mode: `MaxEncodedLen`)
+	/// Storage: `FeeEngine::FixedFees` (r:1 w:0)
+	/// Proof: `FeeEngine::FixedFees` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Balances` (r:1 w:1)
+	/// Proof: `FiStablecoin::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalSupply` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Vaults` (r:1 w:1)
+	/// Proof: `FiStablecoin::Vaults` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalDebt` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalDebt` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn open_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `6208`
+		//  Minimum execution time: 80_000_000 picoseconds.
+		Weight::from_parts(80_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: `FiStablecoin::Vaults` (r:1 w:1)
+	/// Proof: `FiStablecoin::Vaults` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Balances` (r:1 w:1)
+	/// Proof: `FiStablecoin::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalSupply` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalDebt` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalDebt` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn adjust_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `6208`
+		//  Minimum execution time: 65_000_000 picoseconds.
+		Weight::from_parts(65_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `FiStablecoin::Vaults` (r:1 w:1)
+	/// Proof: `FiStablecoin::Vaults` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Balances` (r:1 w:1)
+	/// Proof: `FiStablecoin::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalSupply` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::VaultsByOwner` (r:1 w:1)
+	/// Proof: `FiStablecoin::VaultsByOwner` (`max_values`: None, `max_size`: Some(849), added: 3324, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalDebt` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalDebt` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn close_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `6208`
+		//  Minimum execution time: 70_000_000 picoseconds.
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: `FiStablecoin::Vaults` (r:1 w:1)
+	/// Proof: `FiStablecoin::Vaults` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Balances` (r:1 w:1)
+	/// Proof: `FiStablecoin::Balances` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalSupply` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:4 w:4)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::VaultsByOwner` (r:1 w:1)
+	/// Proof: `FiStablecoin::VaultsByOwner` (`max_values`: None, `max_size`: Some(849), added: 3324, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalDebt` (r:1 w:1)
+	/// Proof: `FiStablecoin::TotalDebt` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn liquidate_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `11426`
+		//  Minimum execution time: 95_000_000 picoseconds.
+		Weight::from_parts(95_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 11426))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: `FiStablecoin::NextVaultId` (r:1 w:0)
+	/// Proof: `FiStablecoin::NextVaultId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::LiquidationCursor` (r:1 w:1)
+	/// Proof: `FiStablecoin::LiquidationCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::Vaults` (r:50 w:0)
+	/// Proof: `FiStablecoin::Vaults` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn liquidation_sweep(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (92 ±0)`
+		//  Estimated: `1493 + n * (2567 ±0)`
+		//  Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(4_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2567).saturating_mul(n.into()))
+	}
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:1)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	fn set_peg_stability_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3636`
+		//  Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3636))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FiStablecoin::GlobalDebtCeiling` (r:0 w:1)
+	/// Proof: `FiStablecoin::GlobalDebtCeiling` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_global_debt_ceiling() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FiStablecoin::MintingPaused` (r:1 w:0)
+	/// Proof: `FiStablecoin::MintingPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::TotalSupply` (r:1 w:0)
+	/// Proof: `FiStablecoin::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::UnbackedIssuance` (r:1 w:0)
+	/// Proof: `FiStablecoin::UnbackedIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::CollateralAssets` (r:11 w:0)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:10 w:0)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn reserve_check() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2310`
+		//  Estimated: `29460`
+		//  Minimum execution time: 96_000_000 picoseconds.
+		Weight::from_parts(96_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 29460))
+			.saturating_add(T::DbWeight::get().reads(24))
+	}
+	/// Storage: `FiStablecoin::MintingPaused` (r:0 w:1)
+	/// Proof: `FiStablecoin::MintingPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_minting_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		//  Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FiStablecoin::Auditors` (r:1 w:1)
+	/// Proof: `FiStablecoin::Auditors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn add_auditor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3513`
+		//  Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FiStablecoin::Auditors` (r:1 w:1)
+	/// Proof: `FiStablecoin::Auditors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_auditor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `74`
+		//  Estimated: `3513`
+		//  Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FiStablecoin::Auditors` (r:1 w:0)
+	/// Proof: `FiStablecoin::Auditors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::CollateralAssets` (r:1 w:0)
+	/// Proof: `FiStablecoin::CollateralAssets` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `FiStablecoin::ReserveAttestations` (r:0 w:1)
+	/// Proof: `FiStablecoin::ReserveAttestations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn attest_reserves() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `270`
+		//  Estimated: `3636`
+		//  Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3636))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
\ No newline at end of file
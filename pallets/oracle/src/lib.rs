@@ -0,0 +1,245 @@
+//! # Oracle Pallet
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! This pallet provides external price data for the CREATEFI blockchain.
+//! A governance-managed set of feeders submits prices per asset. Fresh submissions are aggregated
+//! by median and exposed to other pallets through the [`PriceProvider`] trait.
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::*;
+
+use sp_runtime::FixedU128;
+
+/// Price of one unit of an asset, denominated in FI.
+pub type Price = FixedU128;
+
+/// Interface for reading aggregated prices from the oracle.
+pub trait PriceProvider<Key> {
+    /// Get the current price of an asset, or `None` if it is unknown or stale.
+    fn get_price(key: &Key) -> Option<Price>;
+}
+
+impl<Key> PriceProvider<Key> for () {
+    fn get_price(_key: &Key) -> Option<Price> {
+        None
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{traits::{Saturating, Zero}, FixedPointNumber};
+    use sp_std::vec::Vec;
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching runtime event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        /// The key prices are reported under (e.g. an asset symbol).
+        type OracleKey: Parameter + Member + MaxEncodedLen;
+
+        /// Origin allowed to manage the feeder set.
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The maximum number of feeders.
+        #[pallet::constant]
+        type MaxFeeders: Get<u32>;
+
+        /// The minimum number of fresh submissions needed to publish a price.
+        #[pallet::constant]
+        type MinimumFeeds: Get<u32>;
+
+        /// The number of blocks after which a submission or aggregated price is stale.
+        #[pallet::constant]
+        type MaxPriceAge: Get<BlockNumberFor<Self>>;
+    }
+
+    /// A price together with the block it was recorded at.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct TimestampedPrice<BlockNumber> {
+        pub price: Price,
+        pub updated_at: BlockNumber,
+    }
+
+    /// The pallet's storage items.
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Accounts allowed to submit prices.
+    #[pallet::storage]
+    #[pallet::getter(fn feeders)]
+    pub type Feeders<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxFeeders>, ValueQuery>;
+
+    /// Latest submission of each feeder by key.
+    #[pallet::storage]
+    pub type RawPrices<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::OracleKey,
+        Blake2_128Concat,
+        T::AccountId,
+        TimestampedPrice<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Aggregated median price by key.
+    #[pallet::storage]
+    #[pallet::getter(fn prices)]
+    pub type Prices<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::OracleKey, TimestampedPrice<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Events that functions in this pallet can emit.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A feeder was added.
+        /// [feeder]
+        FeederAdded { feeder: T::AccountId },
+        /// A feeder was removed.
+        /// [feeder]
+        FeederRemoved { feeder: T::AccountId },
+        /// A feeder submitted a price.
+        /// [key, feeder, price]
+        PriceFed {
+            key: T::OracleKey,
+            feeder: T::AccountId,
+            price: Price,
+        },
+        /// The aggregated price of a key was updated.
+        /// [key, price]
+        PriceUpdated { key: T::OracleKey, price: Price },
+    }
+
+    /// Errors that can be returned by this pallet.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account is not a feeder.
+        NotFeeder,
+        /// Account is already a feeder.
+        AlreadyFeeder,
+        /// The feeder set is full.
+        TooManyFeeders,
+        /// Price must be greater than zero.
+        InvalidPrice,
+    }
+
+    /// The pallet's dispatchable functions.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add an account to the feeder set.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_feeder())]
+        pub fn add_feeder(origin: OriginFor<T>, feeder: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            Feeders::<T>::try_mutate(|feeders| -> DispatchResult {
+                ensure!(!feeders.contains(&feeder), Error::<T>::AlreadyFeeder);
+                feeders.try_push(feeder.clone()).map_err(|_| Error::<T>::TooManyFeeders)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::FeederAdded { feeder });
+
+            Ok(())
+        }
+
+        /// Remove an account from the feeder set.
+        ///
+        /// Submissions of removed feeders are ignored by later aggregations.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_feeder())]
+        pub fn remove_feeder(origin: OriginFor<T>, feeder: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            Feeders::<T>::try_mutate(|feeders| -> DispatchResult {
+                let index = feeders.iter().position(|f| *f == feeder).ok_or(Error::<T>::NotFeeder)?;
+                feeders.remove(index);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::FeederRemoved { feeder });
+
+            Ok(())
+        }
+
+        /// Submit a price for a key.
+        ///
+        /// The aggregated price is updated to the median of all fresh submissions once at least
+        /// `MinimumFeeds` feeders have reported.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::feed_price(T::MaxFeeders::get()))]
+        pub fn feed_price(origin: OriginFor<T>, key: T::OracleKey, price: Price) -> DispatchResult {
+            let feeder = ensure_signed(origin)?;
+
+            ensure!(Feeders::<T>::get().contains(&feeder), Error::<T>::NotFeeder);
+            ensure!(!price.is_zero(), Error::<T>::InvalidPrice);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            RawPrices::<T>::insert(&key, &feeder, TimestampedPrice { price, updated_at: now });
+
+            Self::deposit_event(Event::PriceFed { key: key.clone(), feeder, price });
+
+            if let Some(median) = Self::aggregate(&key, now) {
+                Prices::<T>::insert(&key, TimestampedPrice { price: median, updated_at: now });
+                Self::deposit_event(Event::PriceUpdated { key, price: median });
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Median of the fresh submissions of current feeders, if there are enough of them.
+        pub fn aggregate(key: &T::OracleKey, now: BlockNumberFor<T>) -> Option<Price> {
+            let mut prices: Vec<Price> = Feeders::<T>::get()
+                .iter()
+                .filter_map(|feeder| RawPrices::<T>::get(key, feeder))
+                .filter(|raw| !Self::is_stale(raw.updated_at, now))
+                .map(|raw| raw.price)
+                .collect();
+
+            if prices.is_empty() || (prices.len() as u32) < T::MinimumFeeds::get() {
+                return None;
+            }
+
+            prices.sort();
+            let mid = prices.len() / 2;
+            if prices.len().is_multiple_of(2) {
+                let sum = prices[mid - 1].saturating_add(prices[mid]);
+                Some(sum / Price::saturating_from_integer(2u32))
+            } else {
+                Some(prices[mid])
+            }
+        }
+
+        /// Whether a value recorded at `updated_at` is too old at `now`.
+        fn is_stale(updated_at: BlockNumberFor<T>, now: BlockNumberFor<T>) -> bool {
+            now.saturating_sub(updated_at) > T::MaxPriceAge::get()
+        }
+    }
+
+    impl<T: Config> PriceProvider<T::OracleKey> for Pallet<T> {
+        fn get_price(key: &T::OracleKey) -> Option<Price> {
+            let now = frame_system::Pallet::<T>::block_number();
+            Prices::<T>::get(key)
+                .filter(|p| !Self::is_stale(p.updated_at, now))
+                .map(|p| p.price)
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate as pallet_oracle;
+use frame_support::{
+    derive_impl,
+    traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Oracle = pallet_oracle::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+impl pallet_oracle::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type OracleKey = u32;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type MaxFeeders = ConstU32<4>;
+    type MinimumFeeds = ConstU32<2>;
+    type MaxPriceAge = ConstU64<10>;
+}
+
+pub const DOT: u32 = 0;
+pub const FEEDERS: [u64; 3] = [1, 2, 3];
+
+// Build genesis storage according to the mock runtime, with `FEEDERS` registered.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        for feeder in FEEDERS {
+            Oracle::add_feeder(RuntimeOrigin::root(), feeder).unwrap();
+        }
+    });
+    ext
+}
@@ -0,0 +1,93 @@
+use crate::{mock::*, Error, Event, Price, PriceProvider, Prices};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn price(units: u32) -> Price {
+    Price::from_u32(units)
+}
+
+// Submit a price as one of the mock feeders.
+fn feed(feeder: u64, key: u32, units: u32) {
+    assert_ok!(Oracle::feed_price(RuntimeOrigin::signed(feeder), key, price(units)));
+}
+
+#[test]
+fn only_update_origin_manages_feeders() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(Oracle::add_feeder(RuntimeOrigin::signed(1), 4), DispatchError::BadOrigin);
+        assert_noop!(Oracle::add_feeder(RuntimeOrigin::root(), 1), Error::<Test>::AlreadyFeeder);
+
+        assert_ok!(Oracle::add_feeder(RuntimeOrigin::root(), 4));
+        assert_noop!(Oracle::add_feeder(RuntimeOrigin::root(), 5), Error::<Test>::TooManyFeeders);
+
+        assert_ok!(Oracle::remove_feeder(RuntimeOrigin::root(), 4));
+        System::assert_last_event(Event::FeederRemoved { feeder: 4 }.into());
+        assert_noop!(Oracle::remove_feeder(RuntimeOrigin::root(), 4), Error::<Test>::NotFeeder);
+    });
+}
+
+#[test]
+fn feed_price_rejects_non_feeders_and_zero() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Oracle::feed_price(RuntimeOrigin::signed(9), DOT, price(5)),
+            Error::<Test>::NotFeeder
+        );
+        assert_noop!(
+            Oracle::feed_price(RuntimeOrigin::signed(1), DOT, price(0)),
+            Error::<Test>::InvalidPrice
+        );
+    });
+}
+
+#[test]
+fn price_requires_minimum_feeds() {
+    new_test_ext().execute_with(|| {
+        feed(1, DOT, 5);
+        assert_eq!(Prices::<Test>::get(DOT), None);
+        assert_eq!(<Oracle as PriceProvider<u32>>::get_price(&DOT), None);
+
+        feed(2, DOT, 7);
+        System::assert_last_event(Event::PriceUpdated { key: DOT, price: price(6) }.into());
+        assert_eq!(<Oracle as PriceProvider<u32>>::get_price(&DOT), Some(price(6)));
+    });
+}
+
+#[test]
+fn price_is_median_of_feeds() {
+    new_test_ext().execute_with(|| {
+        feed(1, DOT, 5);
+        feed(2, DOT, 100);
+        feed(3, DOT, 6);
+        assert_eq!(<Oracle as PriceProvider<u32>>::get_price(&DOT), Some(price(6)));
+    });
+}
+
+#[test]
+fn stale_feeds_are_ignored() {
+    new_test_ext().execute_with(|| {
+        feed(1, DOT, 5);
+        feed(2, DOT, 7);
+
+        // Feeder 1 and 2 go stale; a single fresh feed is not enough
+        System::set_block_number(12);
+        assert_eq!(<Oracle as PriceProvider<u32>>::get_price(&DOT), None);
+        feed(3, DOT, 9);
+        assert_eq!(<Oracle as PriceProvider<u32>>::get_price(&DOT), None);
+
+        feed(1, DOT, 11);
+        assert_eq!(<Oracle as PriceProvider<u32>>::get_price(&DOT), Some(price(10)));
+    });
+}
+
+#[test]
+fn removed_feeders_are_ignored() {
+    new_test_ext().execute_with(|| {
+        feed(1, DOT, 5);
+        feed(2, DOT, 7);
+        assert_ok!(Oracle::remove_feeder(RuntimeOrigin::root(), 1));
+
+        feed(3, DOT, 9);
+        assert_eq!(<Oracle as PriceProvider<u32>>::get_price(&DOT), Some(price(8)));
+    });
+}
@@ -42,8 +42,8 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, Aura, Balance, Block, Executive, FeeEngine, FiStablecoin, Grandpa, InherentDataExt, Nonce,
+	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -220,6 +220,30 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_fee_engine_runtime_api::FeeEngineApi<Block, AccountId, Balance, RuntimeCall> for Runtime {
+		fn fee(category: pallet_fee_engine::FeeCategory) -> Option<Balance> {
+			FeeEngine::fixed_fees(category)
+		}
+		fn fee_schedule() -> Vec<(pallet_fee_engine::FeeCategory, Balance)> {
+			FeeEngine::fee_schedule()
+		}
+		fn call_fee(who: AccountId, call: RuntimeCall) -> Option<(pallet_fee_engine::FeeCategory, Balance)> {
+			FeeEngine::call_fee(&who, &call)
+		}
+		fn current_revenue_era() -> pallet_fee_engine::EraIndex {
+			FeeEngine::current_revenue_era()
+		}
+		fn era_revenue(era: pallet_fee_engine::EraIndex) -> Vec<(pallet_fee_engine::FeeCategory, Balance)> {
+			FeeEngine::era_revenue_by_category(era)
+		}
+	}
+
+	impl pallet_fi_stablecoin_runtime_api::FiStablecoinApi<Block> for Runtime {
+		fn collateralization_ratio() -> Option<sp_runtime::FixedU128> {
+			FiStablecoin::collateralization_ratio()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -15,20 +15,58 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig};
+use crate::{
+	AccountId, Balance, BalancesConfig, CircuitBreakerConfig, CreateTokenConfig, DaoTreasuryAccount,
+	FeeEngineConfig, FiStablecoinConfig, RuntimeGenesisConfig, SudoConfig, UNIT,
+};
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
+use pallet_fee_engine::{FeeCategory, FeeTierGroup};
 use serde_json::Value;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_genesis_builder::{self, PresetId};
 use sp_keyring::Sr25519Keyring;
 
+// The fixed fee table used by the development and local testnet presets.
+fn testnet_fee_schedule() -> Vec<(FeeCategory, Balance)> {
+	vec![
+		(FeeCategory::GasFee, UNIT / 100),
+		(FeeCategory::BridgeSmall, UNIT / 20),
+		(FeeCategory::BridgeMedium, UNIT / 10),
+		(FeeCategory::BridgeLarge, UNIT / 2),
+		(FeeCategory::NftBridge, UNIT / 20),
+		(FeeCategory::DexTrading, UNIT / 100),
+		(FeeCategory::PoolSmall, UNIT),
+		(FeeCategory::PoolMedium, UNIT * 2),
+		(FeeCategory::PoolLarge, UNIT * 5),
+		(FeeCategory::PoolOperations, UNIT / 100),
+		(FeeCategory::TokenCreation, UNIT / 2),
+		(FeeCategory::NftMintingSmall, UNIT / 20),
+		(FeeCategory::NftMintingMedium, UNIT / 5),
+		(FeeCategory::NftMintingLarge, UNIT),
+		(FeeCategory::NftMintingXLarge, UNIT * 2),
+		(FeeCategory::VaultCreation, UNIT / 20),
+		(FeeCategory::GovernanceProposal, UNIT / 2),
+	]
+}
+
+// The tier thresholds used by the development and local testnet presets.
+fn testnet_tier_thresholds() -> Vec<(FeeTierGroup, Vec<Balance>)> {
+	vec![
+		(FeeTierGroup::Bridge, vec![UNIT * 1_000, UNIT * 100_000]),
+		(FeeTierGroup::Pool, vec![UNIT * 10_000, UNIT * 100_000]),
+		// Payload sizes in bytes: 100 KiB, 1 MiB, 10 MiB
+		(FeeTierGroup::NftMinting, vec![100 * 1024, 1024 * 1024, 10 * 1024 * 1024]),
+	]
+}
+
 // Returns the genesis config presets populated with given parameters.
 fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	endowed_accounts: Vec<AccountId>,
 	root: AccountId,
+	fees: Vec<(FeeCategory, Balance)>,
 ) -> Value {
 	build_struct_json_patch!(RuntimeGenesisConfig {
 		balances: BalancesConfig {
@@ -38,13 +76,35 @@ fn testnet_genesis(
 				.map(|k| (k, 1u128 << 60))
 				.collect::<Vec<_>>(),
 		},
+		fi_stablecoin: FiStablecoinConfig {
+			// 1,000 FI each to pay for gas
+			balances: endowed_accounts
+				.iter()
+				.cloned()
+				.map(|k| (k, 1_000_000_000_000_000u128))
+				.collect::<Vec<_>>(),
+		},
+		create_token: CreateTokenConfig {
+			// 1B CREATE, of which 10M are allocated to each endowed account
+			initial_supply: 1_000_000_000_000_000_000_000_000_000u128,
+			allocations: endowed_accounts
+				.iter()
+				.cloned()
+				.map(|k| (k, 10_000_000_000_000_000_000_000_000u128))
+				.collect::<Vec<_>>(),
+			// The DAO treasury may hold any amount of CREATE
+			wallet_cap_exemptions: vec![DaoTreasuryAccount::get()],
+		},
 		aura: pallet_aura::GenesisConfig {
 			authorities: initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>(),
 		},
 		grandpa: pallet_grandpa::GenesisConfig {
 			authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
 		},
+		// The root key doubles as the circuit breaker guardian on testnets
+		circuit_breaker: CircuitBreakerConfig { guardian: Some(root.clone()) },
 		sudo: SudoConfig { key: Some(root) },
+		fee_engine: FeeEngineConfig { fees, tier_thresholds: testnet_tier_thresholds() },
 	})
 }
 
@@ -62,6 +122,7 @@ pub fn development_config_genesis() -> Value {
 			Sr25519Keyring::BobStash.to_account_id(),
 		],
 		sp_keyring::Sr25519Keyring::Alice.to_account_id(),
+		testnet_fee_schedule(),
 	)
 }
 
@@ -83,6 +144,7 @@ pub fn local_config_genesis() -> Value {
 			.map(|v| v.to_account_id())
 			.collect::<Vec<_>>(),
 		Sr25519Keyring::Alice.to_account_id(),
+		testnet_fee_schedule(),
 	)
 }
 
@@ -13,12 +13,13 @@ use alloc::vec::Vec;
 use sp_runtime::{
 	generic, impl_opaque_keys,
 	traits::{BlakeTwo256, IdentifyAccount, Verify},
-	MultiAddress, MultiSignature,
+	FixedU128, MultiAddress, MultiSignature, Permill,
 };
 use frame_support::{
-	traits::{ConstU32, ConstU128, LockIdentifier},
-	parameter_types,
+	traits::{ConstU32, ConstU128, Contains, EitherOfDiverse, LockIdentifier},
+	parameter_types, PalletId,
 };
+use frame_system::EnsureRoot;
 
 // Define account IDs and lock identifiers using parameter_types
 parameter_types! {
@@ -27,6 +28,12 @@ parameter_types! {
 	pub const StakingLockId: LockIdentifier = [1u8; 8];
 	pub const CreateTokenGovernanceLockId: LockIdentifier = [2u8; 8];
 	pub const DaoGovernanceLockId: LockIdentifier = [3u8; 8];
+	pub const FiStablecoinPalletId: PalletId = PalletId(*b"cfi/fist");
+	pub const FeeEnginePalletId: PalletId = PalletId(*b"cfi/fees");
+	pub MinCollateralRatio: FixedU128 = FixedU128::from_rational(150, 100);
+	pub LiquidationRatio: FixedU128 = FixedU128::from_rational(110, 100);
+	pub const LiquidationPenalty: Permill = Permill::from_percent(10);
+	pub const KeeperReward: Permill = Permill::from_percent(5);
 }
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
@@ -40,6 +47,7 @@ pub use pallet_fee_engine::Call as FeeEngineCall;
 pub use pallet_create_token::Call as CreateTokenCall;
 pub use pallet_dex::Call as DexCall;
 pub use pallet_dao::Call as DaoCall;
+pub use pallet_circuit_breaker::Call as CircuitBreakerCall;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 
@@ -85,12 +93,14 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
-	//   the compatible custom types.
-	spec_version: 100,
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	//   the compatible custom types. Bumped so the storage `Migrations` run on upgrade.
+	spec_version: 101,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped for the fee engine withdrawal calls being merged into `withdraw_fees`, then for
+	//   `Dao::create_proposal` taking the treasury funds to pay from.
+	transaction_version: 3,
 	system_version: 1,
 };
 
@@ -174,6 +184,7 @@ pub type TxExtension = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_fee_engine::ChargeFixedFee<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	frame_system::WeightReclaim<Runtime>,
 );
@@ -189,7 +200,16 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_fee_engine::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_fee_engine::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_fi_stablecoin::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_fi_stablecoin::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_fi_stablecoin::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_create_token::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_create_token::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_dao::migrations::v1::MigrateV0ToV1<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -259,23 +279,116 @@ mod runtime {
 
 	#[runtime::pallet_index(12)]
 	pub type Dao = pallet_dao;
+
+	#[runtime::pallet_index(13)]
+	pub type Assets = pallet_assets;
+
+	#[runtime::pallet_index(14)]
+	pub type Oracle = pallet_oracle;
+
+	#[runtime::pallet_index(15)]
+	pub type CircuitBreaker = pallet_circuit_breaker;
 }
 
 // Configure the pallets
 impl pallet_fi_stablecoin::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type Assets = Assets;
+	type CollateralAdminOrigin = EnsureRoot<AccountId>;
+	type PalletId = FiStablecoinPalletId;
 	type WeightInfo = pallet_fi_stablecoin::weights::SubstrateWeight<Runtime>;
 	type MinCollateralAmount = ConstU128<1_000_000_000_000>; // 1 FI
 	type MaxMintAmount = ConstU128<1_000_000_000_000_000_000_000>; // 1M FI
+	type FeeEngine = FeeEngine;
+	type MinCollateralRatio = MinCollateralRatio; // 150%
+	type MaxVaultsPerAccount = ConstU32<16>;
+	type LiquidationRatio = LiquidationRatio; // 110%
+	type LiquidationPenalty = LiquidationPenalty;
+	type KeeperReward = KeeperReward;
+	type LiquidationBeneficiary = DaoTreasuryAccount;
+	type MaxLiquidationChecks = ConstU32<50>;
+	type PriceProvider = Oracle;
+	type SystemAccounts = FiSystemAccounts;
+	type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+/// Accounts that may send or receive FI through the `fungible` traits.
+pub struct FiSystemAccounts;
+
+impl Contains<AccountId> for FiSystemAccounts {
+	fn contains(account: &AccountId) -> bool {
+		*account == FeeEngine::account_id() || *account == DaoTreasuryAccount::get()
+	}
 }
 
 impl pallet_fee_engine::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type Currency = Balances;
+	type Currency = FiStablecoin;
 	type WeightInfo = pallet_fee_engine::weights::SubstrateWeight<Runtime>;
 	type FounderAccount = FounderAccount;
 	type DaoTreasuryAccount = DaoTreasuryAccount;
+	type CallToFeeType = RuntimeCallToFeeType;
+	type PalletId = FeeEnginePalletId;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type MaxFeeScheduleLen = ConstU32<32>;
+	type MinAnnouncementPeriod = ConstU32<DAYS>;
+	type MaxFeeBeneficiaries = ConstU32<8>;
+	type StakeProvider = CreateTokenStakes;
+	type MaxDiscountTiers = ConstU32<4>;
+	type RevenueEraLength = ConstU32<{ 30 * DAYS }>;
+	type RevenueRetention = ConstU32<24>;
+	type DaoTreasury = DaoTreasuryDeposit;
+	type SweepPeriod = ConstU32<DAYS>;
+}
+
+/// Sweeps the DAO's share of protocol fees, paid in FI, into `pallet_dao`'s fee treasury.
+pub struct DaoTreasuryDeposit;
+
+impl pallet_fee_engine::TreasuryDeposit<AccountId, Balance> for DaoTreasuryDeposit {
+	fn deposit(source: &AccountId, amount: Balance) -> sp_runtime::DispatchResult {
+		Dao::deposit_fees_into_treasury(source, amount)
+	}
+}
+
+/// Feeds CREATE stakes from `pallet_create_token` into the fee engine's staking discounts.
+pub struct CreateTokenStakes;
+
+impl pallet_fee_engine::StakeProvider<AccountId, Balance> for CreateTokenStakes {
+	fn staked_balance(who: &AccountId) -> Balance {
+		pallet_create_token::StakedBalances::<Runtime>::get(who)
+	}
+}
+
+/// Fixed fee types levied on calls by `pallet_fee_engine::ChargeFixedFee`.
+///
+/// Pool creation is tiered by the sum of both initial reserves. Vault creation is charged inside
+/// `pallet_fi_stablecoin` and is therefore not listed here.
+///
+/// Calls dispatched through `sudo` pay the fee of the inner call. `Sudo` is the only pallet of
+/// this runtime that wraps calls; any pallet added later that does, such as `pallet_utility` or
+/// `pallet_proxy`, has to be unwrapped here as well.
+pub struct RuntimeCallToFeeType;
+
+impl pallet_fee_engine::CallToFeeType<RuntimeCall> for RuntimeCallToFeeType {
+	fn fee_type(call: &RuntimeCall) -> Option<pallet_fee_engine::FeeCategory> {
+		match call {
+			RuntimeCall::Sudo(
+				pallet_sudo::Call::sudo { call } |
+				pallet_sudo::Call::sudo_unchecked_weight { call, .. } |
+				pallet_sudo::Call::sudo_as { call, .. },
+			) => Self::fee_type(call),
+			RuntimeCall::Dex(DexCall::amm_trade { .. }) |
+			RuntimeCall::Dex(DexCall::place_order { .. }) => Some(pallet_fee_engine::FeeCategory::DexTrading),
+			RuntimeCall::Dex(DexCall::create_pool { initial_liquidity_a, initial_liquidity_b, .. }) => {
+				let liquidity = initial_liquidity_a.saturating_add(*initial_liquidity_b);
+				Some(FeeEngine::tier_category(pallet_fee_engine::FeeTierGroup::Pool, liquidity))
+			},
+			RuntimeCall::Dex(DexCall::add_liquidity { .. }) => Some(pallet_fee_engine::FeeCategory::PoolOperations),
+			RuntimeCall::Dao(DaoCall::create_proposal { .. }) => Some(pallet_fee_engine::FeeCategory::GovernanceProposal),
+			_ => None,
+		}
+	}
 }
 
 impl pallet_create_token::Config for Runtime {
@@ -286,6 +399,15 @@ impl pallet_create_token::Config for Runtime {
 	type MinGovernanceStake = ConstU128<1_000_000_000_000_000_000_000>; // 1 CREATE
 	type StakingLockId = StakingLockId;
 	type GovernanceLockId = CreateTokenGovernanceLockId;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type UnbondingPeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxUnlockChunks = ConstU32<32>;
+	type MaxVestingSchedules = ConstU32<8>;
+	type MaxWalletCapExemptions = ConstU32<32>;
+	type WalletCapBootstrapSupply = ConstU128<1_000_000_000_000_000_000_000_000>; // 1M CREATE
+	type MintOrigin = EnsureRoot<AccountId>;
+	type MaxSupply = ConstU128<1_000_000_000_000_000_000_000_000_000>; // 1B CREATE
+	type RuntimeHoldReason = RuntimeHoldReason;
 }
 
 impl pallet_dex::Config for Runtime {
@@ -296,7 +418,46 @@ impl pallet_dex::Config for Runtime {
 	type LpFeeBps = ConstU32<25>; // 0.25%
 	type MinLiquidity = ConstU128<1_000_000_000_000_000_000_000>; // 1 token
 	type MaxSlippageBps = ConstU32<500>; // 5%
+	type PriceProvider = Oracle;
+	type MaxPriceDeviationBps = ConstU32<300>; // 3%
+}
+
+/// Oracle prices are keyed by token symbol, e.g. `b"USDC"`.
+pub type OracleKey = frame_support::BoundedVec<u8, ConstU32<32>>;
 
+impl pallet_oracle::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_oracle::weights::SubstrateWeight<Runtime>;
+	type OracleKey = OracleKey;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type MaxFeeders = ConstU32<16>;
+	type MinimumFeeds = ConstU32<3>;
+	type MaxPriceAge = ConstU32<100>; // 10 minutes
+}
+
+impl pallet_circuit_breaker::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_circuit_breaker::weights::SubstrateWeight<Runtime>;
+	type GuardianOrigin = EitherOfDiverse<EnsureRoot<AccountId>, pallet_circuit_breaker::EnsureGuardian<Runtime>>;
+	// `pallet_dao` only executes treasury payouts and cannot dispatch calls, so overrides stay with
+	// root until it can.
+	type OverrideOrigin = EnsureRoot<AccountId>;
+	type WhitelistedPallets = UnpausablePallets;
+	type MaxGuardianPause = ConstU32<{ 3 * DAYS }>;
+	type MaxNameLen = ConstU32<64>;
+}
+
+/// Pallets the circuit breaker never pauses: block production, governance and the breaker
+/// itself must keep working during an incident.
+pub struct UnpausablePallets;
+
+impl Contains<pallet_circuit_breaker::NameOf<Runtime>> for UnpausablePallets {
+	fn contains(pallet: &pallet_circuit_breaker::NameOf<Runtime>) -> bool {
+		matches!(
+			pallet.as_slice(),
+			b"System" | b"Timestamp" | b"Aura" | b"Grandpa" | b"Sudo" | b"CircuitBreaker"
+		)
+	}
 }
 
 impl pallet_dao::Config for Runtime {
@@ -311,4 +472,5 @@ impl pallet_dao::Config for Runtime {
 	type MaxActiveProposals = ConstU32<10>;
 	type TreasuryAccount = DaoTreasuryAccount;
 	type GovernanceLockId = DaoGovernanceLockId;
+	type FeeCurrency = FiStablecoin;
 }
@@ -36,7 +36,7 @@ echo "2. Open https://polkadot.js.org/apps/"
 echo "3. Connect to 'Local Node' (ws://127.0.0.1:9944)"
 echo ""
 echo "📚 Available functions:"
-echo "- Fee Engine: collect_fee, withdraw_founder_fees, withdraw_dao_fees"
+echo "- Fee Engine: collect_fee, withdraw_fees, set_fee_split"
 echo "- CREATE Token: mint_tokens, burn_tokens, transfer_tokens, stake_tokens, unstake_tokens"
 echo "- FI Stablecoin: open_vault, mint_fi, repay_fi, liquidate_vault"
 echo "- DEX: create_pool, add_liquidity, remove_liquidity, amm_trade"